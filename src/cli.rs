@@ -0,0 +1,135 @@
+// src/cli.rs
+//
+// Command-line arguments.
+//
+// The panel only takes a handful of flags, so they're parsed by hand
+// rather than pulling in an argument-parsing crate.
+
+use anyhow::{Result, bail};
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: panel-rs [OPTIONS]
+
+Options:
+  -c, --config <PATH>    Use this config file instead of the XDG one
+      --log-file <PATH>  Also log to this file (or set PANEL_LOG_FILE)
+      --validate         Check the config, print OK or the error, and exit
+      --list-sensors     Print the sensor names items can use, and exit
+  -h, --help             Print this help and exit
+  -V, --version          Print the version and exit";
+
+// What the user asked for on the command line
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    // Overrides the user config normally found under XDG_CONFIG_HOME
+    pub config: Option<PathBuf>,
+    // Log to this file as well as stderr
+    pub log_file: Option<PathBuf>,
+    // Check the config and exit instead of starting the panel
+    pub validate: bool,
+    // Print the discovered sensors and exit
+    pub list_sensors: bool,
+    pub help: bool,
+    pub version: bool,
+}
+
+impl Args {
+    // Parse the process arguments, skipping the program name
+    pub fn from_env() -> Result<Self> {
+        Self::parse(std::env::args_os().skip(1))
+    }
+
+    pub fn parse<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = OsString>,
+    {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.to_str() else {
+                bail!("Unexpected argument {arg:?}\n\n{USAGE}");
+            };
+            match flag {
+                "-c" | "--config" => match args.next() {
+                    Some(path) => parsed.config = Some(PathBuf::from(path)),
+                    None => bail!("{flag} needs a path\n\n{USAGE}"),
+                },
+                "--log-file" => match args.next() {
+                    Some(path) => parsed.log_file = Some(PathBuf::from(path)),
+                    None => bail!("{flag} needs a path\n\n{USAGE}"),
+                },
+                "--validate" => parsed.validate = true,
+                "--list-sensors" => parsed.list_sensors = true,
+                "-h" | "--help" => parsed.help = true,
+                "-V" | "--version" => parsed.version = true,
+                _ => {
+                    if let Some(path) = flag.strip_prefix("--config=") {
+                        parsed.config = Some(PathBuf::from(path));
+                    } else if let Some(path) = flag.strip_prefix("--log-file=") {
+                        parsed.log_file = Some(PathBuf::from(path));
+                    } else {
+                        bail!("Unknown argument {flag:?}\n\n{USAGE}");
+                    }
+                }
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Args;
+    use anyhow::Result;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        Args::parse(args.iter().map(Into::into))
+    }
+
+    #[test]
+    fn no_arguments_keep_defaults() {
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+    }
+
+    #[test]
+    fn parses_config_path() {
+        let expected = Some(PathBuf::from("/tmp/bar.toml"));
+        assert_eq!(
+            parse(&["--config", "/tmp/bar.toml"]).unwrap().config,
+            expected
+        );
+        assert_eq!(parse(&["-c", "/tmp/bar.toml"]).unwrap().config, expected);
+        assert_eq!(parse(&["--config=/tmp/bar.toml"]).unwrap().config, expected);
+    }
+
+    #[test]
+    fn parses_validate_with_config() {
+        let args = parse(&["--validate", "--config", "bar.toml"]).unwrap();
+        assert!(args.validate);
+        assert_eq!(args.config, Some(PathBuf::from("bar.toml")));
+    }
+
+    #[test]
+    fn parses_log_file() {
+        let expected = Some(PathBuf::from("/tmp/panel.log"));
+        assert_eq!(
+            parse(&["--log-file", "/tmp/panel.log"]).unwrap().log_file,
+            expected
+        );
+        assert_eq!(
+            parse(&["--log-file=/tmp/panel.log"]).unwrap().log_file,
+            expected
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(parse(&["--config"]).is_err());
+        assert!(parse(&["--log-file"]).is_err());
+        assert!(parse(&["--frobnicate"]).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+// src/core/actions.rs
+//
+// Click and scroll actions shared by every item.
+//
+// `ActionItem` wraps any `Item` and attaches a `GestureClick` and an
+// `EventControllerScroll` to its root widget, running the command
+// configured in `ItemCommon` for the pressed button or scroll direction.
+// Once a scroll command exits the item updates right away, so e.g. a
+// volume change shows without waiting for the next tick.
+
+use super::config::ItemCommon;
+use super::item::Item;
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{EventControllerScroll, EventControllerScrollFlags, GestureClick, Widget, gio};
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+// GDK button numbers
+const BUTTON_LEFT: u32 = 1;
+const BUTTON_MIDDLE: u32 = 2;
+const BUTTON_RIGHT: u32 = 3;
+
+// An item decorated with the click and scroll commands from its config
+pub struct ActionItem {
+    // Shared with the scroll handler, which updates it
+    inner: Rc<dyn Item>,
+    actions: ItemCommon,
+}
+
+impl ActionItem {
+    // Wrap `inner` if any click or scroll command is configured, otherwise
+    // hand it back untouched so plain items don't pay for unused controllers.
+    pub fn wrap(inner: Box<dyn Item>, actions: &ItemCommon) -> Box<dyn Item> {
+        let has_click = [BUTTON_LEFT, BUTTON_MIDDLE, BUTTON_RIGHT]
+            .into_iter()
+            .any(|button| command_for_button(actions, button).is_some());
+        let has_scroll = actions.on_scroll_up.is_some() || actions.on_scroll_down.is_some();
+        if !has_click && !has_scroll {
+            return inner;
+        }
+        Box::new(ActionItem {
+            inner: Rc::from(inner),
+            actions: actions.clone(),
+        })
+    }
+}
+
+impl Item for ActionItem {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn widget(&self) -> Widget {
+        let widget = self.inner.widget();
+
+        // Button 0 makes the gesture listen to every mouse button
+        let gesture = GestureClick::new();
+        gesture.set_button(0);
+
+        let actions = self.actions.clone();
+        gesture.connect_pressed(move |gesture, _n_press, _x, _y| {
+            if let Some(cmd) = command_for_button(&actions, gesture.current_button()) {
+                spawn_command(cmd);
+            }
+        });
+        widget.add_controller(gesture);
+
+        // DISCRETE turns smooth touchpad scrolling into whole steps, so one
+        // notch runs the command once
+        let scroll = EventControllerScroll::new(
+            EventControllerScrollFlags::VERTICAL | EventControllerScrollFlags::DISCRETE,
+        );
+        let actions = self.actions.clone();
+        let inner = self.inner.clone();
+        scroll.connect_scroll(
+            move |_scroll, _dx, dy| match command_for_scroll(&actions, dy) {
+                Some(cmd) => {
+                    let inner = inner.clone();
+                    spawn_command_then(cmd, move || inner.update());
+                    glib::Propagation::Stop
+                }
+                None => glib::Propagation::Proceed,
+            },
+        );
+        widget.add_controller(scroll);
+
+        widget
+    }
+
+    fn start(&self) -> Result<()> {
+        self.inner.start()
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.inner.refresh_interval()
+    }
+
+    fn update(&self) {
+        self.inner.update()
+    }
+
+    fn always_update(&self) -> bool {
+        self.inner.always_update()
+    }
+
+    fn stop(&self) {
+        self.inner.stop()
+    }
+}
+
+// Pick the configured command for a GDK button number, if any
+pub fn command_for_button(actions: &ItemCommon, button: u32) -> Option<&str> {
+    match button {
+        BUTTON_LEFT => actions.on_click.as_deref(),
+        BUTTON_MIDDLE => actions.on_click_middle.as_deref(),
+        BUTTON_RIGHT => actions.on_click_right.as_deref(),
+        _ => None,
+    }
+}
+
+// Pick the configured command for a vertical scroll delta, if any.
+// GTK reports negative deltas when scrolling up.
+pub fn command_for_scroll(actions: &ItemCommon, dy: f64) -> Option<&str> {
+    if dy < 0.0 {
+        actions.on_scroll_up.as_deref()
+    } else if dy > 0.0 {
+        actions.on_scroll_down.as_deref()
+    } else {
+        None
+    }
+}
+
+// Run `cmd` through `sh -c`, detached from the panel.
+//
+// The child's stdio is discarded and a short-lived thread reaps it so
+// finished commands don't linger as zombies.
+pub fn spawn_command(cmd: &str) {
+    if let Some(mut child) = spawn_child(cmd) {
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
+
+// Run `cmd` like `spawn_command`, then call `done` on the GTK main
+// thread once it has exited
+pub fn spawn_command_then(cmd: &str, done: impl FnOnce() + 'static) {
+    if let Some(mut child) = spawn_child(cmd) {
+        glib::spawn_future_local(async move {
+            let _ = gio::spawn_blocking(move || child.wait()).await;
+            done();
+        });
+    }
+}
+
+// Start `cmd` through `sh -c` with its stdio discarded, logging failures
+fn spawn_child(cmd: &str) -> Option<Child> {
+    let spawned = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    match spawned {
+        Ok(child) => {
+            debug!(command = %cmd, pid = child.id(), "Spawned command");
+            Some(child)
+        }
+        Err(e) => {
+            warn!(command = %cmd, error = %e, "Failed to spawn command");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{command_for_button, command_for_scroll};
+    use crate::core::config::{Config, ItemCommon};
+
+    #[test]
+    fn maps_buttons_to_commands() {
+        let cfg: Config = toml::from_str(
+            r#"
+            items = ["clock"]
+
+            [clock]
+            on_click = "left"
+            on_click_right = "right"
+            "#,
+        )
+        .unwrap();
+        let actions = &cfg.module("clock").parse::<ItemCommon>().unwrap();
+
+        assert_eq!(command_for_button(actions, 1), Some("left"));
+        assert_eq!(command_for_button(actions, 2), None);
+        assert_eq!(command_for_button(actions, 3), Some("right"));
+        assert_eq!(command_for_button(actions, 8), None);
+    }
+
+    #[test]
+    fn maps_scroll_direction_to_commands() {
+        let cfg: Config = toml::from_str(
+            r#"
+            items = ["clock"]
+
+            [clock]
+            on_scroll_up = "up"
+            on_scroll_down = "down"
+            "#,
+        )
+        .unwrap();
+        let actions = &cfg.module("clock").parse::<ItemCommon>().unwrap();
+
+        assert_eq!(command_for_scroll(actions, -1.0), Some("up"));
+        assert_eq!(command_for_scroll(actions, 1.0), Some("down"));
+        assert_eq!(command_for_scroll(actions, 0.0), None);
+    }
+}
@@ -2,10 +2,14 @@
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
-// use std::time::Duration;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use tracing::info;
+use tracing::{info, warn};
 
 use super::config_loader::config_paths;
 
@@ -14,54 +18,576 @@ pub struct Config {
     // Which items to enable in the bar, in order
     pub items: Vec<String>,
 
-    // Refresh interval for items that poll (in seconds)
+    // Refresh interval for items that poll, in seconds; fractions such
+    // as 0.5 are allowed
     #[serde(default = "default_refresh_secs")]
-    pub refresh_secs: u64,
+    pub refresh_secs: f64,
+
+    // Layout of the bar window, from the `[bar]` table
+    #[serde(default)]
+    pub bar: BarConfig,
+
+    // Per-item tables, keyed like the entries in `items`
+    // (e.g. `[clock]` or `["custom/uptime"]`).
+    // Each item deserializes its own settings from its table.
+    #[serde(flatten)]
+    pub modules: HashMap<String, toml::Table>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            items: Vec::new(),
+            refresh_secs: default_refresh_secs(),
+            bar: BarConfig::default(),
+            modules: HashMap::new(),
+        }
+    }
+}
+
+// Settings for the bar window itself.
+//
+// ```toml
+// [bar]
+// orientation = "vertical"
+// position = "right"
+// icon_size = 20
+// icon_fallbacks = ["image-missing"]
+// keyboard_interactivity = "on-demand"
+// layer = "overlay"
+// margin_bottom = 8
+// width = "auto"
+// exclusive_zone = 0
+// ```
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+pub struct BarConfig {
+    // Which way items are laid out
+    pub orientation: BarOrientation,
+    // Screen edge to dock to; defaults to the bottom for a horizontal bar
+    // and the left for a vertical one
+    pub position: Option<BarPosition>,
+    // Size of item icons in logical pixels; GTK scales them for HiDPI
+    pub icon_size: u32,
+    // Icon names tried, in order, after an item's own icons are all
+    // missing from the theme, e.g. `["image-missing"]`
+    pub icon_fallbacks: Vec<String>,
+    // Whether the bar takes keyboard focus; popovers need `on-demand`
+    // to receive typing
+    pub keyboard_interactivity: KeyboardInteractivity,
+    // Layer-shell layer the bar lives on
+    pub layer: BarLayer,
+    // Gaps in pixels between the bar and each screen edge
+    pub margin_top: i32,
+    pub margin_right: i32,
+    pub margin_bottom: i32,
+    pub margin_left: i32,
+    // Length along the docked edge: the whole edge, just the items, or a
+    // fixed number of pixels
+    pub width: BarWidth,
+    // Space reserved along the docked edge, in pixels; unset reserves the
+    // bar's thickness. 0 reserves none, so windows go under the bar, and
+    // -1 also ignores other panels' zones and stretches to the screen edge.
+    pub exclusive_zone: Option<i32>,
+}
+
+impl Default for BarConfig {
+    fn default() -> Self {
+        BarConfig {
+            orientation: BarOrientation::default(),
+            position: None,
+            icon_size: 16,
+            icon_fallbacks: Vec::new(),
+            keyboard_interactivity: KeyboardInteractivity::default(),
+            layer: BarLayer::default(),
+            margin_top: 0,
+            margin_right: 0,
+            margin_bottom: 0,
+            margin_left: 0,
+            width: BarWidth::default(),
+            exclusive_zone: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BarOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+// Layer-shell layers, bottom-most first. Windows on `top` cover normal
+// windows but not fullscreen ones; `overlay` covers everything.
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BarLayer {
+    Background,
+    Bottom,
+    #[default]
+    Top,
+    Overlay,
+}
+
+// `"full"` anchors both ends of the docked edge so the compositor stretches
+// the bar across it; `"auto"` anchors neither and GTK sizes the window to
+// its items.
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(try_from = "RawBarWidth")]
+pub enum BarWidth {
+    #[default]
+    Full,
+    Auto,
+    Pixels(u32),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawBarWidth {
+    Name(String),
+    Pixels(u32),
+}
+
+impl TryFrom<RawBarWidth> for BarWidth {
+    type Error = String;
+
+    fn try_from(raw: RawBarWidth) -> std::result::Result<Self, Self::Error> {
+        match raw {
+            RawBarWidth::Name(name) => match name.as_str() {
+                "full" => Ok(BarWidth::Full),
+                "auto" => Ok(BarWidth::Auto),
+                _ => Err(format!(
+                    "unknown width {name:?}, expected \"full\", \"auto\" or pixels"
+                )),
+            },
+            RawBarWidth::Pixels(px) => Ok(BarWidth::Pixels(px)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyboardInteractivity {
+    // Never focused
+    #[default]
+    None,
+    // Focused when clicked, like a normal window
+    OnDemand,
+    // Grabs all keyboard input while on the top or overlay layer
+    Exclusive,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BarPosition {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl BarConfig {
+    // The edge to dock to, after applying the orientation's default
+    pub fn position(&self) -> BarPosition {
+        self.position.unwrap_or(match self.orientation {
+            BarOrientation::Horizontal => BarPosition::Bottom,
+            BarOrientation::Vertical => BarPosition::Left,
+        })
+    }
+
+    // The two edges at either end of the docked one, anchored together
+    // for a full-width bar
+    pub fn span_edges(&self) -> [BarPosition; 2] {
+        match self.position() {
+            BarPosition::Top | BarPosition::Bottom => [BarPosition::Left, BarPosition::Right],
+            BarPosition::Left | BarPosition::Right => [BarPosition::Top, BarPosition::Bottom],
+        }
+    }
+
+    // (edge, margin) for every edge, top first going clockwise
+    pub fn margins(&self) -> [(BarPosition, i32); 4] {
+        [
+            (BarPosition::Top, self.margin_top),
+            (BarPosition::Right, self.margin_right),
+            (BarPosition::Bottom, self.margin_bottom),
+            (BarPosition::Left, self.margin_left),
+        ]
+    }
+
+    // A horizontal bar belongs on the top or bottom edge, a vertical one
+    // on the left or right; icons need a size and margins can't be negative
+    pub fn validate(&self) -> Result<()> {
+        if self.icon_size == 0 {
+            anyhow::bail!("[bar] icon_size must be at least 1");
+        }
+        if self.width == BarWidth::Pixels(0) {
+            anyhow::bail!("[bar] width must be at least 1 pixel");
+        }
+        if self.exclusive_zone.is_some_and(|zone| zone < -1) {
+            anyhow::bail!("[bar] exclusive_zone must be -1 or more");
+        }
+        for (edge, margin) in self.margins() {
+            if margin < 0 {
+                anyhow::bail!(
+                    "[bar] margin_{} must not be negative",
+                    format!("{edge:?}").to_lowercase()
+                );
+            }
+        }
+        let fits = matches!(
+            (self.orientation, self.position()),
+            (
+                BarOrientation::Horizontal,
+                BarPosition::Top | BarPosition::Bottom
+            ) | (
+                BarOrientation::Vertical,
+                BarPosition::Left | BarPosition::Right
+            )
+        );
+        if !fits {
+            anyhow::bail!(
+                "[bar] position {:?} doesn't fit a {:?} bar",
+                self.position(),
+                self.orientation
+            );
+        }
+        Ok(())
+    }
+}
+
+// Everything an item factory needs to build one entry of `items`
+#[derive(Debug, Clone)]
+pub struct ModuleConfig {
+    // The entry as written in `items`, e.g. "clock" or "custom/uptime"
+    pub key: String,
+    // Global refresh interval, for items that don't set their own
+    pub refresh_secs: f64,
+    // The item's table; empty when the config doesn't have one
+    pub table: toml::Table,
+    // Connector of the monitor the bar is on (e.g. "DP-1"), when known,
+    // for items that differ per output
+    pub monitor: Option<String>,
+}
+
+impl ModuleConfig {
+    // The item type: the part of the key before any `/`
+    pub fn kind(&self) -> &str {
+        self.key.split_once('/').map_or(&self.key, |(kind, _)| kind)
+    }
+
+    // The instance id after `/`, for items that can appear several times
+    pub fn id(&self) -> Option<&str> {
+        self.key.split_once('/').map(|(_, id)| id)
+    }
+
+    // Deserialize the item's table into its typed config
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T> {
+        self.table
+            .clone()
+            .try_into()
+            .with_context(|| format!("Parsing [{}] settings", self.key))
+    }
+}
+
+// Settings shared by every item.
+//
+// These are flattened into each item's own table, so they sit next to the
+// item-specific keys:
+//
+// ```toml
+// [clock]
+// on_click = "gnome-calendar"
+// ```
+//
+// Click and scroll commands are run through `sh -c`, so pipes, `&&` and
+// environment variables work as they would in a shell.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ItemCommon {
+    // Command run on a left click
+    pub on_click: Option<String>,
+    // Command run on a right click
+    pub on_click_right: Option<String>,
+    // Command run on a middle click
+    pub on_click_middle: Option<String>,
+    // Command run for each scroll step up, e.g.
+    // `pactl set-sink-volume @DEFAULT_SINK@ +5%`
+    pub on_scroll_up: Option<String>,
+    // Command run for each scroll step down
+    pub on_scroll_down: Option<String>,
+    // Which side of the label an item's icon sits on
+    #[serde(default)]
+    pub icon_position: IconPosition,
+    // Show the icon, the label or both, for items that have an icon
+    #[serde(default)]
+    pub display: ItemDisplay,
+    // Drop polling ticks while the bar window isn't mapped
+    #[serde(default = "default_true")]
+    pub skip_when_hidden: bool,
+}
+
+impl Default for ItemCommon {
+    fn default() -> Self {
+        ItemCommon {
+            on_click: None,
+            on_click_right: None,
+            on_click_middle: None,
+            on_scroll_up: None,
+            on_scroll_down: None,
+            icon_position: IconPosition::default(),
+            display: ItemDisplay::default(),
+            skip_when_hidden: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ItemDisplay {
+    #[default]
+    Both,
+    Icon,
+    Label,
+}
+
+impl ItemDisplay {
+    pub fn icon(self) -> bool {
+        matches!(self, ItemDisplay::Both | ItemDisplay::Icon)
+    }
+
+    pub fn label(self) -> bool {
+        matches!(self, ItemDisplay::Both | ItemDisplay::Label)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IconPosition {
+    #[default]
+    Left,
+    Right,
+}
+
+impl IconPosition {
+    // `icon` and `label` in the order they should be appended
+    pub fn order<T>(self, icon: T, label: T) -> [T; 2] {
+        match self {
+            IconPosition::Left => [icon, label],
+            IconPosition::Right => [label, icon],
+        }
+    }
+}
+
+// Settings for the `clock` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct ClockConfig {
+    // strftime string or preset ("iso", "24h", "12h") for the label
+    #[serde(default = "default_clock_format")]
+    pub format: String,
+    // Same, for the hover text; no tooltip when unset
+    pub tooltip_format: Option<String>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl Default for ClockConfig {
+    fn default() -> Self {
+        ClockConfig {
+            format: default_clock_format(),
+            tooltip_format: None,
+            common: ItemCommon::default(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_clock_format() -> String {
+    "%H:%M:%S".into()
+}
+
+// `config/default.toml`, for when no system default is installed
+const DEFAULT_CONFIG: &str = include_str!("../../config/default.toml");
+
+// Commented starter config written for new users
+const STARTER_CONFIG: &str = include_str!("../../config/starter.toml");
+
+// How `Config::load_with` finds and prepares the config files
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    // Replaces the XDG user config, e.g. from `--config`
+    pub path: Option<PathBuf>,
+    // Write a commented starter config to the XDG path if there is none.
+    // Off by default so tests and `--validate` never create files.
+    pub write_default_if_missing: bool,
 }
 
 impl Config {
+    // The settings for one entry of `items`
+    pub fn module(&self, key: &str) -> ModuleConfig {
+        ModuleConfig {
+            key: key.to_string(),
+            refresh_secs: self.refresh_secs,
+            table: self.modules.get(key).cloned().unwrap_or_default(),
+            monitor: None,
+        }
+    }
+
     // Loads system default and then overrides with user config, if present
     pub fn load() -> Result<Self> {
-        let (system, user) = config_paths();
-        info!(system = ?system, user = ?user, "Loading configuration paths");
+        Self::load_from(None)
+    }
 
-        // Ensure the user config directory exists
-        if let Some(parent) = user.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("Creating config directory at {parent:?}"))?;
-        }
+    // Like `load`, but `user_path` (e.g. from `--config`) replaces the
+    // XDG user config. An explicit path has to exist.
+    pub fn load_from(user_path: Option<PathBuf>) -> Result<Self> {
+        Self::load_with(&LoadOptions {
+            path: user_path,
+            ..Default::default()
+        })
+    }
 
-        // 1. Read system default (which should always exist in installed package)
-        info!(path = ?system, "Reading system default config");
-        let base = fs::read_to_string(&system)
-            .with_context(|| format!("Reading system default config at {system:?}"))?;
-        let mut cfg: Config = toml::from_str(&base).context("Parsing system default config")?;
+    // Loads the config as described by `options`
+    pub fn load_with(options: &LoadOptions) -> Result<Self> {
+        let (system, xdg_user) = config_paths();
+        let user = match options.path.clone() {
+            Some(path) => {
+                if !path.exists() {
+                    anyhow::bail!("Config file {path:?} does not exist");
+                }
+                path
+            }
+            None => {
+                // Only the starter config needs the directory; reading
+                // (e.g. for `--validate`) leaves the filesystem alone
+                if options.write_default_if_missing && !xdg_user.exists() {
+                    if let Some(parent) = xdg_user.parent() {
+                        fs::create_dir_all(parent)
+                            .with_context(|| format!("Creating config directory at {parent:?}"))?;
+                    }
+                    // A failed write only costs the user the template
+                    match write_starter_config(&xdg_user) {
+                        Ok(()) => info!(path = ?xdg_user, "Wrote starter config"),
+                        Err(e) => {
+                            warn!(path = ?xdg_user, error = %e, "Failed to write starter config")
+                        }
+                    }
+                }
+                xdg_user
+            }
+        };
+        info!(system = ?system, user = ?user, "Loading configuration paths");
+        Self::load_files(&system, &user)
+    }
+
+    // Loads `system` overlaid with `user`. Either file may be missing:
+    // without `system` the default config built into the binary is used.
+    pub fn load_files(system: &Path, user: &Path) -> Result<Self> {
+        // 1. Read system default, falling back to the embedded copy
+        //    (e.g. for a `cargo install`ed binary without assets)
+        let mut cfg: Config = if system.exists() {
+            info!(path = ?system, "Reading system default config");
+            let base = fs::read_to_string(system)
+                .with_context(|| format!("Reading system default config at {system:?}"))?;
+            toml::from_str(&base)
+                .with_context(|| format!("Parsing system default config at {system:?}"))?
+        } else {
+            info!(path = ?system, "No system default config found; using embedded default");
+            toml::from_str(DEFAULT_CONFIG).context("Parsing embedded default config")?
+        };
 
         // 2. If user config exists, merge/override
         if user.exists() {
             info!(path = ?user, "Overlaying user configuration");
-            let overlay = fs::read_to_string(&user)
+            let overlay = fs::read_to_string(user)
                 .with_context(|| format!("Reading user config at {user:?}"))?;
-            let user_cfg: Config = toml::from_str(&overlay).context("Parsing user config")?;
+            let user_cfg: Config = toml::from_str(&overlay)
+                .with_context(|| format!("Parsing user config at {user:?}"))?;
 
-            // Simple merge: replace entire items list & refresh
+            // Simple merge: replace entire items list & refresh,
+            // and any item table the user redefines
             cfg.items = user_cfg.items;
             cfg.refresh_secs = user_cfg.refresh_secs;
+            cfg.bar = user_cfg.bar;
+            cfg.modules.extend(user_cfg.modules);
         } else {
             info!(path = ?user, "No user config found; using defaults");
         }
 
         // 3. Validate config values
-        if cfg.refresh_secs == 0 {
-            Err(anyhow::anyhow!("refresh_secs must be at least 1"))?
-        }
+        refresh_interval("config", cfg.refresh_secs)?;
+        cfg.bar.validate()?;
 
         info!(?cfg, "Configuration loaded succesfully");
         Ok(cfg)
     }
 }
 
-// Default to 1 second if not specified
-fn default_refresh_secs() -> u64 {
-    1
+// Write the starter config to `path`, never replacing an existing file
+pub fn write_starter_config(path: &Path) -> Result<()> {
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(STARTER_CONFIG.as_bytes()))
+        .with_context(|| format!("Writing starter config to {path:?}"))
+}
+
+// Poll interval for items when neither they nor the config set one
+pub const DEFAULT_REFRESH_SECS: f64 = 1.0;
+
+// Shortest poll interval accepted, in seconds
+pub const MIN_REFRESH_SECS: f64 = 0.1;
+
+fn default_refresh_secs() -> f64 {
+    DEFAULT_REFRESH_SECS
+}
+
+// `refresh_secs` as a timer interval, rounded to the millisecond. `owner`
+// names the item (or the config) in the error for out-of-range values.
+pub fn refresh_interval(owner: &str, secs: f64) -> Result<Duration> {
+    if !(secs >= MIN_REFRESH_SECS && secs.is_finite()) {
+        anyhow::bail!("{owner}: refresh_secs must be at least {MIN_REFRESH_SECS}, got {secs}");
+    }
+    Ok(Duration::from_millis((secs * 1000.0).round() as u64))
+}
+
+// Checks an item's own `refresh_secs`, when it sets one, the way
+// `item_refresh_interval` will, without warning about a clamped 0
+pub fn check_refresh_secs(owner: &str, secs: Option<f64>) -> Result<()> {
+    match secs {
+        Some(secs) if secs != 0.0 => refresh_interval(owner, secs).map(drop),
+        _ => Ok(()),
+    }
+}
+
+// Range checks on an item's settings that deserializing can't express.
+//
+// The item's constructor and `--validate` both run them, so a config that
+// validates never has an item dropped for a bad value at startup. They
+// must not touch the host: no files, sockets or commands.
+pub trait ValidateConfig {
+    // `name` is the item's entry in `items`, e.g. "custom/uptime"
+    fn validate(&self, _name: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+// Interval an item's own `refresh_secs = 0` is clamped to
+pub const ZERO_REFRESH_CLAMP_SECS: f64 = 1.0;
+
+// `refresh_interval` for an item's resolved `refresh_secs`. A 0 is
+// clamped to `ZERO_REFRESH_CLAMP_SECS` with a warning rather than
+// dropping the item; other out-of-range values are still errors.
+pub fn item_refresh_interval(owner: &str, secs: f64) -> Result<Duration> {
+    if secs == 0.0 {
+        warn!(
+            item = owner,
+            "refresh_secs = 0, using {ZERO_REFRESH_CLAMP_SECS}s instead"
+        );
+        return refresh_interval(owner, ZERO_REFRESH_CLAMP_SECS);
+    }
+    refresh_interval(owner, secs)
 }
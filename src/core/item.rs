@@ -2,13 +2,17 @@
 
 use anyhow::Result;
 use gtk4::Widget;
+use std::time::Duration;
 
 // Core trait for a status-bar item plugin.
 //
 // Each item must:
 // 1. provide a unique `name()` for identification;
 // 2. build and return its root `Widget` via `widget()`;
-// 3. start its internal logic (timers, event handlers) once mounted.
+// 3. start its internal logic (event handlers) once mounted;
+// 4. refresh itself in `update()` if it polls, at the interval it
+//    reports from `refresh_interval()`; the shared `Scheduler` drives this;
+// 5. tear its logic down again in `stop()`, if it has any.
 pub trait Item {
     // A short, unique identifier for the item
     fn name(&self) -> &str;
@@ -21,6 +25,27 @@ pub trait Item {
     // Kick off any ongoing tasks.
     // Called after the widget is in the widget tree and show.
     fn start(&self) -> Result<()>;
+
+    // How often the scheduler should call `update()`.
+    // `None` means the item doesn't poll.
+    fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    // Refresh the displayed state. Called on every tick of the
+    // item's interval, always on the GTK main thread.
+    fn update(&self) {}
+
+    // Whether `update()` must run even while the bar is hidden, e.g. to
+    // fire a timer's action on time. Other polling items skip ticks then.
+    fn always_update(&self) -> bool {
+        false
+    }
+
+    // Cancel whatever `start()` set up (timers, signal handlers).
+    // Called before the bar is torn down or rebuilt; items without
+    // background work can keep the default no-op.
+    fn stop(&self) {}
 }
 
 #[cfg(test)]
@@ -52,5 +77,11 @@ mod tests {
         assert_eq!(d.name(), "dummy");
         // widget() may return anything that upcasts to Widget
         assert!(d.start().is_ok());
+        // Non-polling items aren't scheduled; update() is a no-op
+        assert_eq!(d.refresh_interval(), None);
+        d.update();
+        assert!(!d.always_update());
+        // stop() defaults to a no-op
+        d.stop();
     }
 }
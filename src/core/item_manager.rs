@@ -1,29 +1,263 @@
 // src/core/item_manager.rs
 
-use super::config::Config;
+use super::actions::ActionItem;
+use super::config::{ClockConfig, Config, ItemCommon, ModuleConfig, ValidateConfig};
 use super::item::Item;
+use super::items::bluetooth::{BluetoothConfig, BluetoothItem};
+use super::items::button::{ActionButtonConfig, ActionButtonItem};
 use super::items::clock::ClockItem;
+use super::items::custom::{CustomConfig, CustomItem};
+use super::items::diskio::{DiskIoConfig, DiskIoItem};
+use super::items::fan::{FanConfig, FanItem};
+use super::items::gpu::{GpuConfig, GpuItem};
+use super::items::idle_inhibitor::{IdleInhibitorConfig, IdleInhibitorItem};
+use super::items::lockkeys::{LockKeysConfig, LockKeysItem};
+use super::items::mic::{MicConfig, MicItem};
+use super::items::mode::{ModeConfig, ModeItem};
+use super::items::netinfo::{NetInfoConfig, NetInfoItem};
+use super::items::nightlight::{NightLightConfig, NightLightItem};
+use super::items::notifications::{NotificationConfig, NotificationItem};
+use super::items::power_menu::{PowerMenuConfig, PowerMenuItem};
+use super::items::power_profile::{PowerProfileConfig, PowerProfileItem};
+use super::items::procs::{ProcsConfig, ProcsItem};
+use super::items::psi::{PsiConfig, PsiItem};
+use super::items::relative::{RelativeConfig, RelativeItem};
+use super::items::spacer::{SpacerConfig, SpacerItem};
+use super::items::sysinfo::{SysInfoConfig, SysInfoItem};
+use super::items::text::{TextConfig, TextItem};
+use super::items::timer::{TimerConfig, TimerItem};
+use super::items::vpn::{VpnConfig, VpnItem};
+use super::items::weather::{WeatherConfig, WeatherItem};
+use super::items::wifi::{WifiConfig, WifiItem};
+use super::items::workspaces::{WorkspacesConfig, WorkspacesItem};
+use super::visibility::VisibleOnly;
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 use tracing::warn;
 
+// Builds an item from its module config
+pub type ItemFactory = Box<dyn Fn(&ModuleConfig) -> Result<Box<dyn Item>>>;
+
+// Parses an item's module config without building the item
+type ConfigCheck = Box<dyn Fn(&ModuleConfig) -> Result<()>>;
+
+// How to check and build one item type
+struct ItemKind {
+    check: ConfigCheck,
+    build: ItemFactory,
+}
+
+thread_local! {
+    // Item types known to `load`, keyed by the name used in `items`.
+    // GTK objects never leave the main thread, so neither does the registry.
+    static REGISTRY: RefCell<HashMap<String, ItemKind>> = RefCell::new(builtin_kinds());
+}
+
+// The item types shipped with the panel
+fn builtin_kinds() -> HashMap<String, ItemKind> {
+    let mut kinds = HashMap::new();
+    add::<ClockConfig>(&mut kinds, "clock", |module, cfg| {
+        Ok(Box::new(ClockItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<CustomConfig>(&mut kinds, "custom", |module, cfg| {
+        Ok(Box::new(CustomItem::new(
+            &module.key,
+            cfg,
+            module.refresh_secs,
+        )?))
+    });
+    add::<FanConfig>(&mut kinds, "fan", |module, cfg| {
+        Ok(Box::new(FanItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<PowerProfileConfig>(&mut kinds, "power_profile", |_, cfg| {
+        Ok(Box::new(PowerProfileItem::new(&cfg)?))
+    });
+    add::<IdleInhibitorConfig>(&mut kinds, "idle_inhibitor", |_, cfg| {
+        Ok(Box::new(IdleInhibitorItem::new(&cfg)?))
+    });
+    add::<LockKeysConfig>(&mut kinds, "lockkeys", |module, cfg| {
+        Ok(Box::new(LockKeysItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<TextConfig>(&mut kinds, "text", |module, cfg| {
+        Ok(Box::new(TextItem::new(&module.key, &cfg)))
+    });
+    add::<SpacerConfig>(&mut kinds, "spacer", |module, cfg| {
+        Ok(Box::new(SpacerItem::new(&module.key, &cfg)))
+    });
+    add::<ActionButtonConfig>(&mut kinds, "button", |module, cfg| {
+        Ok(Box::new(ActionButtonItem::new(&module.key, &cfg)?))
+    });
+    add::<BluetoothConfig>(&mut kinds, "bluetooth", |module, cfg| {
+        Ok(Box::new(BluetoothItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<WeatherConfig>(&mut kinds, "weather", |_, cfg| {
+        Ok(Box::new(WeatherItem::new(&cfg)?))
+    });
+    add::<WifiConfig>(&mut kinds, "wifi", |module, cfg| {
+        Ok(Box::new(WifiItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<DiskIoConfig>(&mut kinds, "diskio", |module, cfg| {
+        Ok(Box::new(DiskIoItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<ProcsConfig>(&mut kinds, "procs", |module, cfg| {
+        Ok(Box::new(ProcsItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<SysInfoConfig>(&mut kinds, "hostname", |_, cfg| {
+        Ok(Box::new(SysInfoItem::hostname(&cfg)?))
+    });
+    add::<SysInfoConfig>(&mut kinds, "kernel", |_, cfg| {
+        Ok(Box::new(SysInfoItem::kernel(&cfg)?))
+    });
+    add::<SysInfoConfig>(&mut kinds, "user", |_, cfg| {
+        Ok(Box::new(SysInfoItem::user(&cfg)?))
+    });
+    add::<PowerMenuConfig>(&mut kinds, "power_menu", |_, cfg| {
+        Ok(Box::new(PowerMenuItem::new(&cfg)))
+    });
+    add::<NetInfoConfig>(&mut kinds, "netinfo", |module, cfg| {
+        Ok(Box::new(NetInfoItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<VpnConfig>(&mut kinds, "vpn", |module, cfg| {
+        Ok(Box::new(VpnItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<PsiConfig>(&mut kinds, "psi", |module, cfg| {
+        Ok(Box::new(PsiItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<RelativeConfig>(&mut kinds, "relative", |module, cfg| {
+        Ok(Box::new(RelativeItem::new(&module.key, &cfg)?))
+    });
+    add::<TimerConfig>(&mut kinds, "timer", |module, cfg| {
+        Ok(Box::new(TimerItem::new(&module.key, &cfg)?))
+    });
+    add::<GpuConfig>(&mut kinds, "gpu", |module, cfg| {
+        Ok(Box::new(GpuItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<MicConfig>(&mut kinds, "mic", |module, cfg| {
+        Ok(Box::new(MicItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<NightLightConfig>(&mut kinds, "nightlight", |module, cfg| {
+        Ok(Box::new(NightLightItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<NotificationConfig>(&mut kinds, "notifications", |module, cfg| {
+        Ok(Box::new(NotificationItem::new(&cfg, module.refresh_secs)?))
+    });
+    add::<WorkspacesConfig>(&mut kinds, "workspaces", |_, cfg| {
+        Ok(Box::new(WorkspacesItem::new(&cfg)?))
+    });
+    add::<ModeConfig>(&mut kinds, "mode", |_, cfg| {
+        Ok(Box::new(ModeItem::new(&cfg)?))
+    });
+    kinds
+}
+
+// Register a built-in item whose settings parse into `C`. `--validate`
+// parses and range-checks them the same way without building the item;
+// the constructors run the same `ValidateConfig` checks.
+fn add<C: DeserializeOwned + ValidateConfig + 'static>(
+    kinds: &mut HashMap<String, ItemKind>,
+    name: &str,
+    build: impl Fn(&ModuleConfig, C) -> Result<Box<dyn Item>> + 'static,
+) {
+    kinds.insert(
+        name.into(),
+        ItemKind {
+            check: Box::new(|module: &ModuleConfig| module.parse::<C>()?.validate(&module.key)),
+            build: Box::new(move |module: &ModuleConfig| build(module, module.parse()?)),
+        },
+    );
+}
+
 // Manages the set of items for the status bar
 pub struct ItemManager {
     items: Vec<Box<dyn Item>>,
 }
 
 impl ItemManager {
+    // Makes a new item type available to `load` under `name`.
+    // Registering an existing name replaces its factory. `validate` can
+    // only check the shared settings of items registered this way.
+    pub fn register<F>(name: impl Into<String>, factory: F)
+    where
+        F: Fn(&ModuleConfig) -> Result<Box<dyn Item>> + 'static,
+    {
+        let kind = ItemKind {
+            check: Box::new(|module: &ModuleConfig| module.parse::<ItemCommon>().map(drop)),
+            build: Box::new(factory),
+        };
+        REGISTRY.with_borrow_mut(|registry| {
+            registry.insert(name.into(), kind);
+        });
+    }
+
+    // Entries of `config.items` whose kind has no registered factory
+    pub fn unknown_items(config: &Config) -> Vec<String> {
+        REGISTRY.with_borrow(|registry| {
+            config
+                .items
+                .iter()
+                .filter(|name| !registry.contains_key(config.module(name).kind()))
+                .cloned()
+                .collect()
+        })
+    }
+
+    // Checks a loaded config beyond what `Config::load` can see on its
+    // own: every item must be known and its settings must parse the way
+    // its factory parses them. No item is built, so nothing touches
+    // GTK, D-Bus or the hardware.
+    pub fn validate(config: &Config) -> Result<()> {
+        let unknown = Self::unknown_items(config);
+        if !unknown.is_empty() {
+            anyhow::bail!("Unknown items in config: {}", unknown.join(", "));
+        }
+        for name in &config.items {
+            let module = config.module(name);
+            REGISTRY
+                .with_borrow(|registry| match registry.get(module.kind()) {
+                    Some(kind) => (kind.check)(&module),
+                    None => Ok(()),
+                })
+                .and_then(|()| module.parse::<ItemCommon>().map(drop))
+                .with_context(|| format!("Invalid settings for item {name:?}"))?;
+        }
+        Ok(())
+    }
+
     // Loads all enabled items in the order specified by the config.
     pub fn load(config: &Config) -> Self {
+        Self::load_on(config, None)
+    }
+
+    // Like `load`, telling every item which monitor the bar is on
+    pub fn load_on(config: &Config, monitor: Option<&str>) -> Self {
         let mut items: Vec<Box<dyn Item>> = Vec::new();
 
         for name in &config.items {
-            match name.as_str() {
-                "clock" => {
-                    // Create a ClockItem with the configured refresh rate
-                    let clock = ClockItem::new(config.refresh_secs as u32);
-                    items.push(Box::new(clock));
+            let mut module = config.module(name);
+            module.monitor = monitor.map(str::to_string);
+
+            let built = REGISTRY.with_borrow(|registry| {
+                registry.get(module.kind()).map(|kind| {
+                    let item = (kind.build)(&module)?;
+                    let common = module.parse::<ItemCommon>()?;
+                    Ok::<_, anyhow::Error>((item, common))
+                })
+            });
+
+            match built {
+                Some(Ok((item, common))) => {
+                    // Attach the shared click actions the same way for every item
+                    let item = ActionItem::wrap(item, &common);
+                    items.push(VisibleOnly::wrap(item, &common));
+                }
+                Some(Err(e)) => {
+                    warn!(item = %name, error = %e, "Failed to create item, skipping");
                 }
-                other => {
-                    warn!(item = %other, "Unknown item in config, skipping");
+                None => {
+                    warn!(item = %name, "Unknown item in config, skipping");
                 }
             }
         }
@@ -34,18 +268,54 @@ impl ItemManager {
     pub fn items(&self) -> &[Box<dyn Item>] {
         &self.items
     }
+
+    // Mutable access for rebuilding the set in place, e.g. on reload.
+    // Stop the scheduler first: it holds indices into this list.
+    pub fn items_mut(&mut self) -> &mut Vec<Box<dyn Item>> {
+        &mut self.items
+    }
+
+    // Append an item after the existing ones
+    pub fn push(&mut self, item: Box<dyn Item>) {
+        self.items.push(item);
+    }
+
+    // Drop every item, stopping each one first
+    pub fn clear(&mut self) {
+        for item in &self.items {
+            item.stop();
+        }
+        self.items.clear();
+    }
+
+    // Indices into `items()` of every polling item, grouped by
+    // refresh interval, so the scheduler needs one timer per interval
+    pub fn intervals(&self) -> BTreeMap<Duration, Vec<usize>> {
+        let mut groups: BTreeMap<Duration, Vec<usize>> = BTreeMap::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if let Some(interval) = item.refresh_interval() {
+                groups.entry(interval).or_default().push(index);
+            }
+        }
+        groups
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::ItemManager;
     use crate::core::config::Config;
+    use crate::core::item::Item;
+    use anyhow::Result;
+    use gtk4::prelude::Cast;
+    use std::time::Duration;
 
     #[test]
     fn load_empty_list() {
         let cfg = Config {
             items: vec![],
-            refresh_secs: 1,
+            refresh_secs: 1.0,
+            ..Default::default()
         };
         let manager = ItemManager::load(&cfg);
         assert!(manager.items().is_empty());
@@ -55,11 +325,233 @@ mod tests {
     fn preserves_order() {
         let cfg = Config {
             items: vec!["clock".into(), "unknown".into(), "clock".into()],
-            refresh_secs: 5,
+            refresh_secs: 5.0,
+            ..Default::default()
         };
         let manager = ItemManager::load(&cfg);
         assert_eq!(manager.items().len(), 2);
         assert_eq!(manager.items()[0].name(), "clock");
         assert_eq!(manager.items()[1].name(), "clock");
     }
+
+    struct NamedItem(String);
+    impl Item for NamedItem {
+        fn name(&self) -> &str {
+            &self.0
+        }
+        fn refresh_interval(&self) -> Option<Duration> {
+            self.0.parse().ok().map(Duration::from_secs_f64)
+        }
+        fn widget(&self) -> gtk4::Widget {
+            // Never called here, so just a placeholder
+            gtk4::Box::new(gtk4::Orientation::Horizontal, 0).upcast()
+        }
+        fn start(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn registered_factory_is_used() {
+        ItemManager::register("named", |module| {
+            Ok(Box::new(NamedItem(module.id().unwrap_or("none").into())))
+        });
+
+        let cfg = Config {
+            items: vec!["named/a".into(), "clock".into(), "named".into()],
+            ..Default::default()
+        };
+        let manager = ItemManager::load(&cfg);
+        let names: Vec<_> = manager.items().iter().map(|i| i.name()).collect();
+        assert_eq!(names, ["a", "clock", "none"]);
+    }
+
+    #[test]
+    fn factories_see_the_monitor() {
+        ItemManager::register("output", |module| {
+            Ok(Box::new(NamedItem(
+                module.monitor.clone().unwrap_or("none".into()),
+            )))
+        });
+
+        let cfg = Config {
+            items: vec!["output".into()],
+            ..Default::default()
+        };
+        let manager = ItemManager::load_on(&cfg, Some("DP-1"));
+        assert_eq!(manager.items()[0].name(), "DP-1");
+        let manager = ItemManager::load(&cfg);
+        assert_eq!(manager.items()[0].name(), "none");
+    }
+
+    #[test]
+    fn failing_factory_skips_item() {
+        ItemManager::register("broken", |_| anyhow::bail!("no backend"));
+
+        let cfg = Config {
+            items: vec!["broken".into(), "clock".into()],
+            ..Default::default()
+        };
+        let manager = ItemManager::load(&cfg);
+        assert_eq!(manager.items().len(), 1);
+        assert_eq!(manager.items()[0].name(), "clock");
+    }
+
+    #[test]
+    fn custom_items_are_keyed_by_id() {
+        let cfg: Config = toml::from_str(
+            r#"
+            items = ["custom/uptime", "custom/missing"]
+
+            ["custom/uptime"]
+            command = "uptime -p"
+            "#,
+        )
+        .unwrap();
+        let manager = ItemManager::load(&cfg);
+        // "custom/missing" has no command and is skipped
+        assert_eq!(manager.items().len(), 1);
+        assert_eq!(manager.items()[0].name(), "custom/uptime");
+    }
+
+    #[test]
+    fn per_item_refresh_overrides_global() {
+        let cfg: Config = toml::from_str(
+            r#"
+            items = ["procs", "custom/x", "mic"]
+            refresh_secs = 2
+
+            [procs]
+            refresh_secs = 5
+
+            ["custom/x"]
+            command = "true"
+
+            [mic]
+            refresh_secs = 0
+            "#,
+        )
+        .unwrap();
+        // None of these touch the host when built, unlike e.g. `psi`
+        let manager = ItemManager::load(&cfg);
+        // `mic`'s zero interval is clamped rather than dropping the item
+        let intervals: Vec<_> = manager
+            .items()
+            .iter()
+            .map(|i| (i.name(), i.refresh_interval()))
+            .collect();
+        assert_eq!(
+            intervals,
+            [
+                ("procs", Some(Duration::from_secs(5))),
+                ("custom/x", Some(Duration::from_secs(2))),
+                ("mic", Some(Duration::from_secs(1))),
+            ]
+        );
+    }
+
+    #[test]
+    fn groups_polling_items_by_interval() {
+        // NamedItem polls every `<id>` seconds when the id is a number
+        ItemManager::register("every", |module| {
+            Ok(Box::new(NamedItem(module.id().unwrap_or("none").into())))
+        });
+
+        let cfg = Config {
+            items: vec![
+                "every/5".into(),
+                "every/1".into(),
+                "every/none".into(),
+                "every/5".into(),
+                "every/0.5".into(),
+            ],
+            ..Default::default()
+        };
+        let manager = ItemManager::load(&cfg);
+        let groups: Vec<_> = manager.intervals().into_iter().collect();
+        assert_eq!(
+            groups,
+            [
+                (Duration::from_millis(500), vec![4]),
+                (Duration::from_secs(1), vec![1]),
+                (Duration::from_secs(5), vec![0, 3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn push_and_clear_keep_order() {
+        let cfg = Config {
+            items: vec!["clock".into()],
+            ..Default::default()
+        };
+        let mut manager = ItemManager::load(&cfg);
+        manager.push(Box::new(NamedItem("a".into())));
+        manager.push(Box::new(NamedItem("b".into())));
+        let names: Vec<_> = manager.items().iter().map(|i| i.name()).collect();
+        assert_eq!(names, ["clock", "a", "b"]);
+
+        manager.items_mut().remove(0);
+        assert_eq!(manager.items()[0].name(), "a");
+
+        manager.clear();
+        assert!(manager.items().is_empty());
+        manager.push(Box::new(NamedItem("c".into())));
+        assert_eq!(manager.items()[0].name(), "c");
+    }
+
+    #[test]
+    fn validate_rejects_unknown_items() {
+        let cfg = Config {
+            items: vec!["clock".into(), "bogus".into(), "custom/x".into()],
+            ..Default::default()
+        };
+        assert_eq!(ItemManager::unknown_items(&cfg), ["bogus"]);
+        assert!(ItemManager::validate(&cfg).is_err());
+
+        let cfg = Config {
+            items: vec!["clock".into(), "spacer/gap".into()],
+            ..Default::default()
+        };
+        assert!(ItemManager::validate(&cfg).is_ok());
+    }
+
+    #[test]
+    fn validate_parses_each_items_own_settings() {
+        let parse = |toml: &str| toml::from_str::<Config>(toml).unwrap();
+
+        // No `command`, which only CustomConfig requires
+        let cfg = parse("items = [\"custom/x\"]\n[\"custom/x\"]\nrefresh_secs = 5\n");
+        let err = ItemManager::validate(&cfg).unwrap_err();
+        assert!(format!("{err:#}").contains("command"), "{err:#}");
+
+        let cfg = parse("items = [\"power_menu\"]\n[power_menu]\nconfirm = \"nope\"\n");
+        assert!(ItemManager::validate(&cfg).is_err());
+
+        let cfg = parse(
+            "items = [\"custom/x\", \"power_menu\"]\n\
+             [\"custom/x\"]\ncommand = \"true\"\n\
+             [power_menu]\nconfirm = [\"reboot\"]\n",
+        );
+        assert!(ItemManager::validate(&cfg).is_ok());
+    }
+
+    #[test]
+    fn validate_runs_each_items_range_checks() {
+        let parse = |toml: &str| toml::from_str::<Config>(toml).unwrap();
+        let invalid = [
+            "items = [\"procs\"]\n[procs]\nrefresh_secs = 0.01\n",
+            "items = [\"custom/x\"]\n[\"custom/x\"]\ncommand = \"true\"\ntimeout_secs = 0\n",
+            "items = [\"timer\"]\n[timer]\nduration_secs = 0\n",
+            "items = [\"nightlight\"]\n[nightlight]\non_temp = 100\n",
+            "items = [\"relative\"]\n[relative]\nsince = \"yesterday\"\n",
+        ];
+        for toml in invalid {
+            assert!(ItemManager::validate(&parse(toml)).is_err(), "{toml}");
+        }
+
+        // A zero interval is clamped at load, so it validates
+        let cfg = parse("items = [\"procs\"]\n[procs]\nrefresh_secs = 0\n");
+        assert!(ItemManager::validate(&cfg).is_ok());
+    }
 }
@@ -0,0 +1,241 @@
+// src/core/items/bluetooth/item.rs
+//
+// A status-bar item showing the Bluetooth adapter's power state and the
+// number of connected devices, updating every `refresh_secs` seconds.
+//
+// BlueZ exports adapters and devices through the ObjectManager at `/`,
+// so one `GetManagedObjects` call per tick covers everything.
+
+use super::status::{Adapter, BluetoothStatus, Device, adapter_status};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result, bail};
+use gtk4::gio::{self, BusType, DBusCallFlags, DBusProxy, DBusProxyFlags};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Image, Label, Widget};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+const BUS_NAME: &str = "org.bluez";
+const OBJECT_PATH: &str = "/";
+const INTERFACE: &str = "org.freedesktop.DBus.ObjectManager";
+
+// Settings for the `bluetooth` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct BluetoothConfig {
+    // Adapter to show, e.g. "hci0"; defaults to the first one
+    pub adapter: Option<String>,
+    // How often (in seconds) to re-read BlueZ; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for BluetoothConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// BluetoothItem shows a Bluetooth icon and the connected-device count
+pub struct BluetoothItem {
+    proxy: DBusProxy,
+    adapter: Option<String>,
+    // How often to re-read BlueZ
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+    // A GetManagedObjects call is still in flight
+    busy: Rc<Cell<bool>>,
+}
+
+impl BluetoothItem {
+    // Connect to BlueZ on the system bus. Fails when bluetoothd isn't
+    // running or the adapter is missing, so the item is skipped.
+    pub fn new(cfg: &BluetoothConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("bluetooth")?;
+        let refresh = item_refresh_interval(
+            "bluetooth",
+            cfg.refresh_secs.unwrap_or(default_refresh_secs),
+        )?;
+
+        let proxy = DBusProxy::for_bus_sync(
+            BusType::System,
+            DBusProxyFlags::DO_NOT_LOAD_PROPERTIES | DBusProxyFlags::DO_NOT_CONNECT_SIGNALS,
+            None,
+            BUS_NAME,
+            OBJECT_PATH,
+            INTERFACE,
+            gio::Cancellable::NONE,
+        )
+        .context("Connecting to BlueZ")?;
+
+        if proxy.name_owner().is_none() {
+            bail!("bluetoothd is not running");
+        }
+
+        // Check the adapter up front rather than showing N/A forever
+        let reply = proxy
+            .call_sync(
+                "GetManagedObjects",
+                None,
+                DBusCallFlags::NONE,
+                -1,
+                gio::Cancellable::NONE,
+            )
+            .context("Listing Bluetooth adapters")?;
+        let (adapters, devices) = parse_managed_objects(&reply);
+        if adapter_status(&adapters, &devices, cfg.adapter.as_deref()).is_none() {
+            match &cfg.adapter {
+                Some(name) => bail!("No Bluetooth adapter named {name:?}"),
+                None => bail!("No Bluetooth adapter found"),
+            }
+        }
+
+        Ok(Self {
+            proxy,
+            adapter: cfg.adapter.clone(),
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            busy: Rc::new(Cell::new(false)),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &BluetoothConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Split a `GetManagedObjects` reply, `(a{oa{sa{sv}}})`, into adapters
+// and devices
+fn parse_managed_objects(reply: &glib::Variant) -> (Vec<Adapter>, Vec<Device>) {
+    let mut adapters = Vec::new();
+    let mut devices = Vec::new();
+
+    for entry in reply.child_value(0).iter() {
+        let Some(path) = entry.child_value(0).str().map(str::to_string) else {
+            continue;
+        };
+        let Some(interfaces) = entry
+            .child_value(1)
+            .get::<HashMap<String, glib::VariantDict>>()
+        else {
+            continue;
+        };
+
+        let flag = |props: &glib::VariantDict, name: &str| {
+            props.lookup::<bool>(name).ok().flatten().unwrap_or(false)
+        };
+        if let Some(props) = interfaces.get("org.bluez.Adapter1") {
+            adapters.push(Adapter {
+                path: path.clone(),
+                powered: flag(props, "Powered"),
+            });
+        }
+        if let Some(props) = interfaces.get("org.bluez.Device1") {
+            devices.push(Device {
+                path,
+                connected: flag(props, "Connected"),
+            });
+        }
+    }
+    (adapters, devices)
+}
+
+// Show the adapter's status on the icon and label
+fn update_widgets(image: &Image, label: &Label, status: &BluetoothStatus) {
+    layout::set_icon(image, &status.icons());
+    label.set_text(&status.label());
+    label.set_visible(!status.label().is_empty());
+    label.set_tooltip_text(Some(&status.tooltip()));
+    image.set_tooltip_text(Some(&status.tooltip()));
+}
+
+impl Item for BluetoothItem {
+    fn name(&self) -> &str {
+        "bluetooth"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("bluetooth-label");
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Show the state right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        let Some((image, label)) = self.widgets.borrow().clone() else {
+            return;
+        };
+        if self.busy.replace(true) {
+            return;
+        }
+
+        let adapter = self.adapter.clone();
+        let busy = self.busy.clone();
+        self.proxy.call(
+            "GetManagedObjects",
+            None,
+            DBusCallFlags::NONE,
+            -1,
+            gio::Cancellable::NONE,
+            move |result| {
+                busy.set(false);
+                let status = result
+                    .context("Querying BlueZ")
+                    .map(|reply| parse_managed_objects(&reply))
+                    .and_then(|(adapters, devices)| {
+                        adapter_status(&adapters, &devices, adapter.as_deref())
+                            .context("Bluetooth adapter disappeared")
+                    });
+                match status {
+                    Ok(status) => {
+                        clear_error(&label);
+                        update_widgets(&image, &label, &status);
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to read Bluetooth status");
+                        label.set_visible(true);
+                        show_error(&label, "BT N/A", &e);
+                    }
+                }
+            },
+        );
+    }
+}
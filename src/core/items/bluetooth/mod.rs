@@ -0,0 +1,9 @@
+// src/core/items/bluetooth/mod.rs
+//! Shows whether Bluetooth is on and how many devices are connected,
+//! as reported by BlueZ on the system bus.
+
+mod item;
+mod status;
+
+pub use item::{BluetoothConfig, BluetoothItem};
+pub use status::{Adapter, BluetoothStatus, Device, adapter_status};
@@ -0,0 +1,146 @@
+// src/core/items/bluetooth/status.rs
+//
+// Boiling BlueZ's managed objects down to what the item shows.
+
+// An `org.bluez.Adapter1` object, e.g. `/org/bluez/hci0`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Adapter {
+    pub path: String,
+    pub powered: bool,
+}
+
+// An `org.bluez.Device1` object, e.g. `/org/bluez/hci0/dev_AA_BB_..`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Device {
+    pub path: String,
+    pub connected: bool,
+}
+
+// What the item shows for one adapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BluetoothStatus {
+    pub powered: bool,
+    pub connected: usize,
+}
+
+impl BluetoothStatus {
+    // Themed icon names, best first
+    pub fn icons(&self) -> [&'static str; 2] {
+        if self.powered {
+            ["bluetooth-active-symbolic", "bluetooth-symbolic"]
+        } else {
+            ["bluetooth-disabled-symbolic", "bluetooth-symbolic"]
+        }
+    }
+
+    // The connected-device count, or nothing when there is none to show
+    pub fn label(&self) -> String {
+        if self.powered && self.connected > 0 {
+            self.connected.to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    pub fn tooltip(&self) -> String {
+        match (self.powered, self.connected) {
+            (false, _) => "Bluetooth off".to_string(),
+            (true, 0) => "Bluetooth on".to_string(),
+            (true, 1) => "1 device connected".to_string(),
+            (true, n) => format!("{n} devices connected"),
+        }
+    }
+}
+
+// The status of the adapter named `wanted` (e.g. "hci0"), or of the
+// first adapter by path when none is configured. `None` when there is no
+// such adapter.
+pub fn adapter_status(
+    adapters: &[Adapter],
+    devices: &[Device],
+    wanted: Option<&str>,
+) -> Option<BluetoothStatus> {
+    let adapter = match wanted {
+        Some(name) => adapters
+            .iter()
+            .find(|a| a.path.rsplit('/').next() == Some(name)),
+        None => adapters.iter().min_by(|a, b| a.path.cmp(&b.path)),
+    }?;
+
+    // Devices live below the adapter that sees them
+    let prefix = format!("{}/", adapter.path);
+    let connected = devices
+        .iter()
+        .filter(|d| d.connected && d.path.starts_with(&prefix))
+        .count();
+    Some(BluetoothStatus {
+        powered: adapter.powered,
+        connected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Adapter, BluetoothStatus, Device, adapter_status};
+
+    fn adapter(path: &str, powered: bool) -> Adapter {
+        Adapter {
+            path: path.to_string(),
+            powered,
+        }
+    }
+
+    fn device(path: &str, connected: bool) -> Device {
+        Device {
+            path: path.to_string(),
+            connected,
+        }
+    }
+
+    #[test]
+    fn counts_connected_devices_of_the_chosen_adapter() {
+        let adapters = [
+            adapter("/org/bluez/hci1", false),
+            adapter("/org/bluez/hci0", true),
+        ];
+        let devices = [
+            device("/org/bluez/hci0/dev_AA", true),
+            device("/org/bluez/hci0/dev_BB", false),
+            device("/org/bluez/hci0/dev_CC", true),
+            device("/org/bluez/hci1/dev_DD", true),
+        ];
+
+        let default = adapter_status(&adapters, &devices, None).unwrap();
+        assert_eq!(
+            default,
+            BluetoothStatus {
+                powered: true,
+                connected: 2
+            }
+        );
+
+        let hci1 = adapter_status(&adapters, &devices, Some("hci1")).unwrap();
+        assert!(!hci1.powered);
+        assert_eq!(hci1.connected, 1);
+
+        assert_eq!(adapter_status(&adapters, &devices, Some("hci2")), None);
+        assert_eq!(adapter_status(&[], &devices, None), None);
+    }
+
+    #[test]
+    fn labels_only_powered_connections() {
+        let off = BluetoothStatus {
+            powered: false,
+            connected: 1,
+        };
+        assert_eq!(off.label(), "");
+        assert_eq!(off.tooltip(), "Bluetooth off");
+
+        let two = BluetoothStatus {
+            powered: true,
+            connected: 2,
+        };
+        assert_eq!(two.label(), "2");
+        assert_eq!(two.tooltip(), "2 devices connected");
+    }
+}
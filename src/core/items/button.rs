@@ -0,0 +1,107 @@
+// src/core/items/button.rs
+//
+// A label-less launcher: a configured icon that runs a shell command
+// on click, e.g. a region screenshot with `grim -g "$(slurp)"`.
+
+use crate::core::actions::spawn_command;
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Widget};
+use serde::Deserialize;
+
+// Settings for a `button` or `button/<id>` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct ActionButtonConfig {
+    // Icon name to show, e.g. "camera-photo-symbolic"
+    pub icon: String,
+    // Shell command run detached on click
+    pub command: String,
+    pub tooltip: Option<String>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for ActionButtonConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        if self.command.trim().is_empty() {
+            bail!("{name}: command must not be empty");
+        }
+        Ok(())
+    }
+}
+
+// ActionButtonItem runs its command whenever it's clicked
+pub struct ActionButtonItem {
+    // Config entry this item was built from, e.g. "button/screenshot"
+    name: String,
+    icon: String,
+    command: String,
+    tooltip: Option<String>,
+}
+
+impl ActionButtonItem {
+    pub fn new(name: &str, cfg: &ActionButtonConfig) -> Result<Self> {
+        cfg.validate(name)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            icon: cfg.icon.clone(),
+            command: cfg.command.clone(),
+            tooltip: cfg.tooltip.clone(),
+        })
+    }
+}
+
+impl Item for ActionButtonItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+        container.add_css_class("action-button");
+        container.set_tooltip_text(self.tooltip.as_deref());
+
+        let image = layout::icon();
+        layout::set_icon(&image, &[self.icon.as_str()]);
+        container.append(&image);
+
+        let gesture = GestureClick::new();
+        let command = self.command.clone();
+        gesture.connect_released(move |_, _, _, _| spawn_command(&command));
+        container.add_controller(gesture);
+
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Nothing to poll
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActionButtonConfig, ActionButtonItem};
+
+    fn parse(toml: &str) -> Result<ActionButtonConfig, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    #[test]
+    fn requires_icon_and_command() {
+        let cfg = parse("icon = \"camera-photo-symbolic\"\ncommand = \"grim\"").unwrap();
+        assert!(cfg.tooltip.is_none());
+        assert!(ActionButtonItem::new("button/shot", &cfg).is_ok());
+
+        assert!(parse("icon = \"camera-photo-symbolic\"").is_err());
+        assert!(parse("command = \"grim\"").is_err());
+
+        let blank = parse("icon = \"x\"\ncommand = \"  \"").unwrap();
+        let err = ActionButtonItem::new("button/shot", &blank).err().unwrap();
+        assert_eq!(err.to_string(), "button/shot: command must not be empty");
+    }
+}
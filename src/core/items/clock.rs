@@ -3,30 +3,92 @@
 // A status-bar item displaying the current local time,
 // updating every `refresh_secs` seconds.
 
+use super::super::config::{ClockConfig, DEFAULT_REFRESH_SECS, ValidateConfig, refresh_interval};
 use super::super::item::Item;
-use anyhow::Result;
+use super::super::layout;
+use anyhow::{Result, bail};
 use chrono::Local;
-use glib::ControlFlow;
-use glib::source::timeout_add_seconds_local;
+use chrono::format::{Item as FormatItem, StrftimeItems};
 use gtk4::prelude::*;
-use gtk4::{Box as GtkBox, Label, Orientation, Widget};
+use gtk4::{Box as GtkBox, Label, Widget};
+use std::time::Duration;
 
-// ClockItem show `HH:MM:SS` and refreshes periodically
+// ClockItem shows the time in its `format` and refreshes periodically
 pub struct ClockItem {
-    // How often (in seconds) to update the displayed time
-    refresh_secs: u32,
+    // How often to update the displayed time
+    refresh: Duration,
+    // strftime strings, presets already expanded
+    format: String,
+    tooltip_format: Option<String>,
     // Lazily initialize the GTK Label widget we'll update on each tick.
     label: std::cell::RefCell<Option<Label>>,
 }
 
 impl ClockItem {
     // Create a new ClockItem with the given refresh interval.
-    pub fn new(refresh_secs: u32) -> Self {
+    pub fn new(cfg: &ClockConfig, refresh_secs: f64) -> Result<Self> {
+        let refresh = refresh_interval("clock", refresh_secs)?;
+        let format = checked_format(&cfg.format)?;
+        let tooltip_format = cfg
+            .tooltip_format
+            .as_deref()
+            .map(checked_format)
+            .transpose()?;
+
         // Initialise the Label now, text will be set in widget()/start()
-        Self {
-            refresh_secs,
+        Ok(Self {
+            refresh,
+            format,
+            tooltip_format,
             label: std::cell::RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval
+    pub fn with_defaults(cfg: &ClockConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+
+    // Show the current time on the label and in its tooltip
+    fn update_text(&self, label: &Label) {
+        let now = Local::now();
+        label.set_text(&now.format(&self.format).to_string());
+        if let Some(tooltip) = &self.tooltip_format {
+            label.set_tooltip_text(Some(&now.format(tooltip).to_string()));
+        }
+    }
+}
+
+// The strftime string a preset name stands for; anything else is
+// taken as a strftime string already
+pub fn expand_preset(format: &str) -> &str {
+    match format {
+        "iso" => "%Y-%m-%dT%H:%M:%S",
+        "24h" => "%H:%M",
+        "12h" => "%I:%M %p",
+        other => other,
+    }
+}
+
+// Expand `format` and reject strftime strings chrono can't render,
+// which would otherwise panic on the first tick
+fn checked_format(format: &str) -> Result<String> {
+    let format = expand_preset(format);
+    if StrftimeItems::new(format).any(|item| matches!(item, FormatItem::Error)) {
+        bail!("clock: invalid format {format:?}");
+    }
+    Ok(format.to_string())
+}
+
+// `ClockConfig` lives with the shared settings; its checks live here
+// next to the format parsing they share with `ClockItem::new`
+impl ValidateConfig for ClockConfig {
+    fn validate(&self, _name: &str) -> Result<()> {
+        checked_format(&self.format)?;
+        if let Some(tooltip) = &self.tooltip_format {
+            checked_format(tooltip)?;
         }
+        Ok(())
     }
 }
 
@@ -37,7 +99,7 @@ impl Item for ClockItem {
 
     fn widget(&self) -> Widget {
         // Build a container forthe clock (in case we add icons or padding)
-        let container = GtkBox::new(Orientation::Horizontal, 4);
+        let container = GtkBox::new(layout::orientation(), 4);
 
         // Lazily initialize the Label
         let label = {
@@ -51,8 +113,7 @@ impl Item for ClockItem {
         label.style_context().add_class("clock-label");
 
         // Set initial text
-        let now = Local::now().format("%H:%M:%S").to_string();
-        label.set_text(&now);
+        self.update_text(&label);
         // Pack the label into the box
         container.append(&label);
         // Return as a generic Widget
@@ -60,24 +121,47 @@ impl Item for ClockItem {
     }
 
     fn start(&self) -> Result<()> {
-        let interval = self.refresh_secs;
-
-        // Grab the initialized Label - panic if widget() wasn't called
-        let label = self
-            .label
-            .borrow()
-            .as_ref()
-            .expect("widget() must be called before start()")
-            .clone();
-
-        // Schedule a repeating timeout on the main context
-        timeout_add_seconds_local(interval, move || {
-            // Update the label text on each tick
-            let now_str = Local::now().format("%H:%M:%S").to_string();
-            // SAFETY: we're in the GTK main thread
-            label.set_text(&now_str);
-            ControlFlow::Continue
-        });
+        // Ticking is driven by the shared scheduler via update()
         Ok(())
     }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            self.update_text(label);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClockItem, expand_preset};
+    use crate::core::config::ClockConfig;
+
+    #[test]
+    fn expands_presets() {
+        assert_eq!(expand_preset("iso"), "%Y-%m-%dT%H:%M:%S");
+        assert_eq!(expand_preset("24h"), "%H:%M");
+        assert_eq!(expand_preset("12h"), "%I:%M %p");
+        assert_eq!(expand_preset("%A %d %B %Y"), "%A %d %B %Y");
+    }
+
+    #[test]
+    fn rejects_invalid_formats() {
+        let cfg = ClockConfig {
+            tooltip_format: Some("%A %d %B %Y".into()),
+            ..Default::default()
+        };
+        assert!(ClockItem::new(&cfg, 1.0).is_ok());
+
+        let cfg = ClockConfig {
+            format: "%H:%Q".into(),
+            ..Default::default()
+        };
+        let err = ClockItem::new(&cfg, 1.0).err().unwrap();
+        assert_eq!(err.to_string(), "clock: invalid format \"%H:%Q\"");
+    }
 }
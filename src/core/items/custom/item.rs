@@ -0,0 +1,458 @@
+// src/core/items/custom/item.rs
+//
+// A status-bar item displaying the output of a shell command,
+// re-running it every `refresh_secs` seconds.
+
+use super::output::{CustomOutput, parse_output};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::level::{LevelConfig, LevelDisplay, level_bar, set_level};
+use crate::core::utils::markup::set_label_text;
+use crate::core::utils::marquee::ScrollingLabel;
+use crate::core::utils::sparkline::{History, SparklineConfig, sparkline_area};
+use crate::core::utils::status::{clear_error, show_error};
+use crate::core::utils::tolerant::{ErrorTolerant, default_max_consecutive_errors};
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, DrawingArea, Label, LevelBar, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::Read;
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for a `custom/<id>` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomConfig {
+    // Command to run through `sh -c`
+    pub command: String,
+    // How often (in seconds) to re-run the command; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    // Parse stdout as `{"text": .., "tooltip": .., "class": .., "percentage": ..}`
+    // instead of showing its first line
+    #[serde(default)]
+    pub json: bool,
+    // Treat the output text as Pango markup
+    #[serde(default)]
+    pub markup: bool,
+    // Scroll output longer than `max_len` characters instead of letting
+    // it stretch the bar; plain text only
+    #[serde(default)]
+    pub scroll: bool,
+    #[serde(default = "default_max_len")]
+    pub max_len: usize,
+    // Text, level bar or both; the bar follows the JSON `percentage`
+    #[serde(default)]
+    pub level: LevelConfig,
+    // Graph of recent `percentage` values
+    #[serde(flatten)]
+    pub sparkline: SparklineConfig,
+    // Seconds the command may run before it's killed
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    // Failed runs in a row to ride out before showing N/A
+    #[serde(default = "default_max_consecutive_errors")]
+    pub max_consecutive_errors: u32,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for CustomConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        if self.command.trim().is_empty() {
+            bail!("{name}: command must not be empty");
+        }
+        check_refresh_secs(name, self.refresh_secs)?;
+        if self.timeout_secs == 0 {
+            bail!("{name}: timeout_secs must be at least 1");
+        }
+        if self.scroll && self.markup {
+            bail!("{name}: scroll can't be combined with markup");
+        }
+        if self.scroll && self.max_len == 0 {
+            bail!("{name}: max_len must be at least 1");
+        }
+        Ok(())
+    }
+}
+
+fn default_max_len() -> usize {
+    30
+}
+
+fn default_timeout_secs() -> u64 {
+    5
+}
+
+// CustomItem shows a command's output and re-runs it periodically
+pub struct CustomItem {
+    // Config entry this item was built from, e.g. "custom/uptime"
+    name: String,
+    command: String,
+    timeout: Duration,
+    json: bool,
+    markup: bool,
+    // Marquee width in characters, when scrolling
+    scroll: Option<usize>,
+    level: LevelConfig,
+    sparkline: SparklineConfig,
+    // How often to re-run the command
+    refresh: Duration,
+    // Lazily initialize the GTK widgets we'll update on each run.
+    widgets: RefCell<Option<Widgets>>,
+    // Shared with in-flight command runs
+    state: Rc<RunState>,
+}
+
+// The label, plus a level bar and sparkline when configured
+#[derive(Clone)]
+struct Widgets {
+    // The item's root, hidden while the command prints nothing
+    root: GtkBox,
+    label: Label,
+    scroller: Option<ScrollingLabel>,
+    bar: Option<LevelBar>,
+    sparkline: Option<(DrawingArea, Rc<RefCell<History>>)>,
+    display: LevelDisplay,
+    markup: bool,
+}
+
+// State shared between scheduler ticks and in-flight command runs
+struct RunState {
+    // A run is still in flight; skip ticks until it finishes
+    busy: Cell<bool>,
+    // Last good output, kept through transient failures
+    output: RefCell<ErrorTolerant<CustomOutput>>,
+    // CSS class applied by the previous JSON output, removed on the next
+    last_class: RefCell<Option<String>>,
+}
+
+impl CustomItem {
+    // Create a new CustomItem named after its config entry.
+    // `default_refresh_secs` is used when the config doesn't set one.
+    pub fn new(name: &str, cfg: CustomConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate(name)?;
+        let refresh =
+            item_refresh_interval(name, cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            command: cfg.command,
+            timeout: Duration::from_secs(cfg.timeout_secs),
+            json: cfg.json,
+            markup: cfg.markup,
+            scroll: cfg.scroll.then_some(cfg.max_len),
+            level: cfg.level,
+            sparkline: cfg.sparkline,
+            refresh,
+            widgets: RefCell::new(None),
+            state: Rc::new(RunState {
+                busy: Cell::new(false),
+                output: RefCell::new(ErrorTolerant::new(cfg.max_consecutive_errors)),
+                last_class: RefCell::new(None),
+            }),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(name: &str, cfg: CustomConfig) -> Result<Self> {
+        Self::new(name, cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+impl Item for CustomItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        // Lazily initialize the widgets
+        let widgets = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                let display = self.level.display;
+                let label = Label::new(None);
+                *slot = Some(Widgets {
+                    root: GtkBox::new(layout::orientation(), 4),
+                    scroller: self
+                        .scroll
+                        .map(|max_len| ScrollingLabel::new(&label, max_len)),
+                    label,
+                    bar: display.bar().then(|| level_bar(&self.level)),
+                    sparkline: self.sparkline.sparkline.then(|| {
+                        let history = Rc::new(RefCell::new(History::new(self.sparkline.history)));
+                        (sparkline_area(&history), history)
+                    }),
+                    display,
+                    markup: self.markup,
+                });
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        widgets.label.add_css_class("custom-label");
+        widgets.label.set_visible(widgets.display.text());
+
+        let container = &widgets.root;
+        container.append(&widgets.label);
+        if let Some(bar) = &widgets.bar {
+            container.append(bar);
+        }
+        if let Some((area, _)) = &widgets.sparkline {
+            container.append(area);
+        }
+        container.clone().upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Run once right away so the label isn't empty until the first tick
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(widgets) = self.widgets.borrow().as_ref() {
+            spawn_run(widgets, &self.command, self.timeout, self.json, &self.state);
+        }
+    }
+
+    fn stop(&self) {
+        // The marquee's timeout would otherwise outlive the item
+        let widgets = self.widgets.borrow();
+        if let Some(scroller) = widgets.as_ref().and_then(|w| w.scroller.as_ref()) {
+            scroller.stop();
+        }
+    }
+}
+
+// Run the command on a worker thread and apply its output back on the
+// GTK main thread. Does nothing while a previous run is still going.
+fn spawn_run(
+    widgets: &Widgets,
+    command: &str,
+    timeout: Duration,
+    json: bool,
+    state: &Rc<RunState>,
+) {
+    if state.busy.replace(true) {
+        return;
+    }
+
+    let widgets = widgets.clone();
+    let command = command.to_string();
+    let state = state.clone();
+    glib::spawn_future_local(async move {
+        let cmd = command.clone();
+        let result = gio::spawn_blocking(move || run_command(&cmd, timeout))
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("command thread panicked")))
+            .and_then(|stdout| parse_output(&stdout, json));
+
+        if let Err(e) = &result {
+            warn!(command = %command, error = %e, "Custom command failed");
+        }
+        match state.output.borrow_mut().record(result) {
+            Ok(output) => {
+                // No output hides the item, e.g. a battery script that
+                // prints nothing while full on AC
+                widgets.root.set_visible(!output.is_empty());
+                clear_error(&widgets.label);
+                widgets.label.set_visible(widgets.display.text());
+                apply_output(&widgets, output, &state.last_class);
+                if let Some(bar) = &widgets.bar {
+                    bar.set_tooltip_text(output.tooltip.as_deref());
+                    if let Some(percent) = output.percentage {
+                        set_level(bar, percent);
+                    }
+                }
+                if let (Some((area, history)), Some(percent)) =
+                    (&widgets.sparkline, output.percentage)
+                {
+                    history.borrow_mut().push(percent);
+                    area.queue_draw();
+                }
+            }
+            Err(e) => {
+                // Errors show on the label even when only the bar is used
+                widgets.root.set_visible(true);
+                widgets.label.set_visible(true);
+                if let Some(scroller) = &widgets.scroller {
+                    scroller.stop();
+                }
+                let short = if e.is::<TimedOut>() { "Timeout" } else { "N/A" };
+                show_error(&widgets.label, short, &e);
+            }
+        }
+        state.busy.set(false);
+    });
+}
+
+// A command that ran past its `timeout_secs` and was killed
+#[derive(Debug)]
+struct TimedOut(Duration);
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "timed out after {}s", self.0.as_secs())
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+// Signal number (the same on every Linux architecture)
+const SIGKILL: i32 = 9;
+
+unsafe extern "C" {
+    // kill(2); a negative pid signals that whole process group
+    fn kill(pid: i32, sig: i32) -> i32;
+}
+
+// Run `command` through `sh -c` and return its stdout, killing it if it
+// runs longer than `timeout`. The shell gets its own process group so
+// the kill also reaches pipelines and backgrounded children, which
+// would otherwise keep the output pipe open.
+fn run_command(command: &str, timeout: Duration) -> Result<String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .process_group(0)
+        .spawn()
+        .with_context(|| format!("Running `{command}`"))?;
+
+    // Collect both pipes on their own threads so neither can fill up
+    // and stall the child; stdout's reader reports back once both close
+    let (mut stdout, mut stderr) = (child.stdout.take().unwrap(), child.stderr.take().unwrap());
+    let stderr_reader = thread::spawn(move || {
+        let mut err = Vec::new();
+        let _ = stderr.read_to_end(&mut err);
+        err
+    });
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut out = Vec::new();
+        let read = stdout.read_to_end(&mut out).map(|_| out);
+        let err = stderr_reader.join().unwrap_or_default();
+        let _ = tx.send(read.map(|out| (out, err)));
+    });
+
+    let (stdout, stderr) = match rx.recv_timeout(timeout) {
+        Ok(read) => read.with_context(|| format!("Reading output of `{command}`"))?,
+        Err(_) => {
+            // The group id is the shell's pid, as it leads the group
+            let pgid = child.id() as i32;
+            // SAFETY: kill(2) only takes plain integers
+            unsafe { kill(-pgid, SIGKILL) };
+            let _ = child.wait();
+            return Err(
+                anyhow::Error::new(TimedOut(timeout)).context(format!("Running `{command}`"))
+            );
+        }
+    };
+    let status = child
+        .wait()
+        .with_context(|| format!("Waiting for `{command}`"))?;
+
+    if !status.success() && stdout.is_empty() {
+        bail!(
+            "`{command}` exited with {status}: {}",
+            String::from_utf8_lossy(&stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&stdout).into_owned())
+}
+
+// Show `output` on the label, swapping the previous CSS class for the new one
+fn apply_output(widgets: &Widgets, output: &CustomOutput, last_class: &RefCell<Option<String>>) {
+    let label = &widgets.label;
+    match &widgets.scroller {
+        Some(scroller) => scroller.set_text(&output.text),
+        None => set_label_text(label, &output.text, widgets.markup),
+    }
+    label.set_tooltip_text(output.tooltip.as_deref());
+
+    let mut last = last_class.borrow_mut();
+    if let Some(old) = last.take() {
+        label.remove_css_class(&old);
+    }
+    if let Some(class) = &output.class {
+        label.add_css_class(class);
+    }
+    *last = output.class.clone();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimedOut, run_command};
+    use std::fs;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn returns_stdout() {
+        let out = run_command("echo hello", Duration::from_secs(5)).unwrap();
+        assert_eq!(out, "hello\n");
+        let err = run_command("echo oops >&2; exit 3", Duration::from_secs(5))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("oops"));
+    }
+
+    #[test]
+    fn kills_commands_past_the_timeout() {
+        let started = Instant::now();
+        let err = run_command("sleep 10", Duration::from_secs(1))
+            .err()
+            .unwrap();
+        assert!(started.elapsed() < Duration::from_secs(3));
+        assert!(err.is::<TimedOut>());
+        assert_eq!(
+            format!("{:#}", err),
+            "Running `sleep 10`: timed out after 1s"
+        );
+    }
+
+    #[test]
+    fn kills_backgrounded_children_on_timeout() {
+        let pid_file = std::env::temp_dir().join(format!("panel-rs-custom-{}", std::process::id()));
+        let command = format!(
+            "sleep 60 & echo $! > {}; wait; echo done",
+            pid_file.display()
+        );
+        let started = Instant::now();
+        let err = run_command(&command, Duration::from_secs(1)).err().unwrap();
+        assert!(started.elapsed() < Duration::from_secs(3));
+        assert!(err.is::<TimedOut>());
+
+        let pid = fs::read_to_string(&pid_file).unwrap();
+        fs::remove_file(&pid_file).unwrap();
+        // The grandchild is gone, or a zombie nobody has reaped yet
+        let stat = format!("/proc/{}/stat", pid.trim());
+        let dead = || {
+            fs::read_to_string(&stat)
+                .map(|s| s.contains(") Z "))
+                .unwrap_or(true)
+        };
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while !dead() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(dead(), "sleep {} survived the timeout", pid.trim());
+    }
+}
@@ -0,0 +1,19 @@
+// src/core/items/custom/mod.rs
+//! A waybar-style custom item: runs a shell command on an interval and
+//! shows its output.
+//!
+//! Configured per instance with `custom/<id>` entries:
+//!
+//! ```toml
+//! items = ["custom/uptime"]
+//!
+//! ["custom/uptime"]
+//! command = "uptime -p"
+//! refresh_secs = 60
+//! ```
+
+mod item;
+mod output;
+
+pub use item::{CustomConfig, CustomItem};
+pub use output::{CustomOutput, parse_output};
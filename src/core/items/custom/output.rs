@@ -0,0 +1,99 @@
+// src/core/items/custom/output.rs
+//
+// Parsing of a custom command's stdout into what the item displays.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+// What a custom item shows after one run of its command
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub struct CustomOutput {
+    // Label text
+    #[serde(default)]
+    pub text: String,
+    // Hover text, if any
+    #[serde(default)]
+    pub tooltip: Option<String>,
+    // Extra CSS class for the label, e.g. "warning"
+    #[serde(default)]
+    pub class: Option<String>,
+    // 0-100 value for the level bar, if the item shows one
+    #[serde(default)]
+    pub percentage: Option<f64>,
+}
+
+impl CustomOutput {
+    // Nothing to show: the item hides until the command prints again
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty() && self.percentage.is_none()
+    }
+}
+
+// Turn the command's stdout into a `CustomOutput`.
+//
+// In plain mode the first line becomes the label. In JSON mode the whole
+// output must be an object like
+// `{"text": "..", "tooltip": "..", "class": "..", "percentage": 42}`;
+// every field is optional.
+pub fn parse_output(stdout: &str, json: bool) -> Result<CustomOutput> {
+    if json {
+        serde_json::from_str(stdout.trim()).context("Parsing command output as JSON")
+    } else {
+        let text = stdout.lines().next().unwrap_or_default().trim_end();
+        Ok(CustomOutput {
+            text: text.to_string(),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CustomOutput, parse_output};
+
+    #[test]
+    fn plain_output_uses_first_line() {
+        let out = parse_output("up 3 hours  \nsecond line\n", false).unwrap();
+        assert_eq!(out.text, "up 3 hours");
+        assert_eq!(out.tooltip, None);
+        assert_eq!(out.class, None);
+    }
+
+    #[test]
+    fn plain_output_may_be_empty() {
+        let out = parse_output("", false).unwrap();
+        assert_eq!(out, CustomOutput::default());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn percentage_alone_is_not_empty() {
+        let out = parse_output(r#"{"text": "", "percentage": 80}"#, true).unwrap();
+        assert!(!out.is_empty());
+    }
+
+    #[test]
+    fn json_output_sets_all_fields() {
+        let out = parse_output(
+            r#"{"text": "42%", "tooltip": "disk usage", "class": "warning", "percentage": 42}"#,
+            true,
+        )
+        .unwrap();
+        assert_eq!(out.text, "42%");
+        assert_eq!(out.tooltip.as_deref(), Some("disk usage"));
+        assert_eq!(out.class.as_deref(), Some("warning"));
+        assert_eq!(out.percentage, Some(42.0));
+    }
+
+    #[test]
+    fn json_fields_are_optional() {
+        let out = parse_output("{\"text\": \"ok\"}\n", true).unwrap();
+        assert_eq!(out.text, "ok");
+        assert_eq!(out.tooltip, None);
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(parse_output("not json", true).is_err());
+    }
+}
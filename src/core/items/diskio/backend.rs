@@ -0,0 +1,166 @@
+// src/core/items/diskio/backend.rs
+//
+// Reads a device's sector counters from `/proc/diskstats` and turns two
+// snapshots into bytes per second.
+
+use crate::core::utils::bytes::human_bytes;
+use anyhow::{Context, Result, bail};
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+// Overrides the diskstats file, mainly for tests
+pub const PROC_DISKSTATS_ENV: &str = "PROC_DISKSTATS";
+const PROC_DISKSTATS: &str = "/proc/diskstats";
+// diskstats counts 512-byte sectors whatever the device's block size
+const SECTOR_SIZE: u64 = 512;
+
+// Cumulative sector counters for one device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskStat {
+    pub sectors_read: u64,
+    pub sectors_written: u64,
+}
+
+// The counters of `device` (e.g. "nvme0n1") in a diskstats dump
+pub fn parse_diskstats(raw: &str, device: &str) -> Result<DiskStat> {
+    for line in raw.lines() {
+        // major minor name reads merged sectors ms writes merged sectors ..
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        if columns.get(2) != Some(&device) {
+            continue;
+        }
+        let sectors = |i: usize, what: &str| -> Result<u64> {
+            let raw = columns
+                .get(i)
+                .with_context(|| format!("Missing {what} for {device}"))?;
+            raw.parse()
+                .with_context(|| format!("Invalid {what} {raw:?} for {device}"))
+        };
+        return Ok(DiskStat {
+            sectors_read: sectors(5, "sectors read")?,
+            sectors_written: sectors(9, "sectors written")?,
+        });
+    }
+    bail!("No device {device:?} in diskstats")
+}
+
+// (read, write) bytes per second between two snapshots `elapsed` apart.
+// Counters that went backwards (a device re-plugged) count as idle.
+pub fn throughput(prev: DiskStat, cur: DiskStat, elapsed: Duration) -> (f64, f64) {
+    let secs = elapsed.as_secs_f64();
+    if secs <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let rate = |before: u64, after: u64| (after.saturating_sub(before) * SECTOR_SIZE) as f64 / secs;
+    (
+        rate(prev.sectors_read, cur.sectors_read),
+        rate(prev.sectors_written, cur.sectors_written),
+    )
+}
+
+// A byte rate with binary units, e.g. "1.5 MiB/s"
+pub fn human_rate(bytes_per_sec: f64, precision: usize) -> String {
+    format!(
+        "{}/s",
+        human_bytes(bytes_per_sec.max(0.0) as u64, precision)
+    )
+}
+
+pub struct DiskIoBackend {
+    path: PathBuf,
+    device: String,
+    // The previous snapshot and when it was taken
+    prev: RefCell<(DiskStat, Instant)>,
+}
+
+impl DiskIoBackend {
+    // Read `/proc/diskstats` (honours `PROC_DISKSTATS`). Fails when the
+    // device isn't listed.
+    pub fn new(device: &str) -> Result<Self> {
+        let path = std::env::var_os(PROC_DISKSTATS_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(PROC_DISKSTATS));
+        let first = Self::snapshot(&path, device)?;
+        Ok(Self {
+            path,
+            device: device.to_string(),
+            prev: RefCell::new((first, Instant::now())),
+        })
+    }
+
+    fn snapshot(path: &Path, device: &str) -> Result<DiskStat> {
+        let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+        parse_diskstats(&raw, device)
+    }
+
+    // (read, write) bytes per second since the previous call
+    pub fn read(&self) -> Result<(f64, f64)> {
+        let cur = Self::snapshot(&self.path, &self.device)?;
+        let now = Instant::now();
+        let (prev, then) = self.prev.replace((cur, now));
+        Ok(throughput(prev, cur, now - then))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiskStat, human_rate, parse_diskstats, throughput};
+    use std::time::Duration;
+
+    const DISKSTATS: &str = "\
+ 259       0 nvme0n1 48210 1534 3954102 9041 90215 51233 6518764 61502 0 62104 72841 0 0 0 0 2714 2297
+ 259       1 nvme0n1p1 312 0 16046 41 2 0 2 0 0 54 42 0 0 0 0 0 0
+   7       0 loop0 41 0 682 5 0 0 0 0 0 12 5 0 0 0 0 0 0
+";
+
+    #[test]
+    fn parses_the_named_device() {
+        let stat = parse_diskstats(DISKSTATS, "nvme0n1").unwrap();
+        assert_eq!(
+            stat,
+            DiskStat {
+                sectors_read: 3954102,
+                sectors_written: 6518764
+            }
+        );
+        assert_eq!(
+            parse_diskstats(DISKSTATS, "nvme0n1p1")
+                .unwrap()
+                .sectors_read,
+            16046
+        );
+        assert!(parse_diskstats(DISKSTATS, "sda").is_err());
+        assert!(parse_diskstats("8 0 sda 1 2\n", "sda").is_err());
+    }
+
+    #[test]
+    fn computes_bytes_per_second() {
+        let prev = DiskStat {
+            sectors_read: 1000,
+            sectors_written: 500,
+        };
+        let cur = DiskStat {
+            sectors_read: 3048,
+            sectors_written: 500,
+        };
+        // 2048 sectors of 512 bytes over two seconds
+        assert_eq!(
+            throughput(prev, cur, Duration::from_secs(2)),
+            (524288.0, 0.0)
+        );
+        // Counters reset: treat as idle rather than underflowing
+        assert_eq!(throughput(cur, prev, Duration::from_secs(1)), (0.0, 0.0));
+        assert_eq!(throughput(prev, cur, Duration::ZERO), (0.0, 0.0));
+    }
+
+    #[test]
+    fn formats_rates() {
+        assert_eq!(human_rate(0.0, 1), "0 B/s");
+        assert_eq!(human_rate(1023.0, 1), "1023 B/s");
+        assert_eq!(human_rate(1536.0, 1), "1.5 KiB/s");
+        assert_eq!(human_rate(524288.0, 1), "512.0 KiB/s");
+        assert_eq!(human_rate(1536.0, 2), "1.50 KiB/s");
+    }
+}
@@ -0,0 +1,132 @@
+// src/core/items/diskio/item.rs
+//
+// A status-bar item displaying a block device's read and write
+// throughput, updating every `refresh_secs` seconds.
+
+use super::backend::{DiskIoBackend, human_rate};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::bytes::default_precision;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for the `diskio` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct DiskIoConfig {
+    // Device name as listed in /proc/diskstats, e.g. "nvme0n1"
+    pub device: String,
+    // How often (in seconds) to re-read the counters; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    // Decimals shown for KiB/s and up
+    #[serde(default = "default_precision")]
+    pub precision: usize,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for DiskIoConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// DiskIoItem shows `R {read} W {write}` in bytes per second
+pub struct DiskIoItem {
+    backend: DiskIoBackend,
+    // How often to update the displayed rates
+    refresh: Duration,
+    precision: usize,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+}
+
+impl DiskIoItem {
+    // Fails when the device isn't in /proc/diskstats, so the item is
+    // skipped.
+    pub fn new(cfg: &DiskIoConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("diskio")?;
+        let refresh =
+            item_refresh_interval("diskio", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        Ok(Self {
+            backend: DiskIoBackend::new(&cfg.device)?,
+            refresh,
+            precision: cfg.precision,
+            label: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &DiskIoConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Refresh the label from the backend
+fn update_label(label: &Label, backend: &DiskIoBackend, precision: usize) {
+    match backend.read() {
+        Ok((read, write)) => {
+            clear_error(label);
+            label.set_text(&format!(
+                "R {} W {}",
+                human_rate(read, precision),
+                human_rate(write, precision)
+            ));
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to read disk I/O");
+            show_error(label, "Disk N/A", &e);
+        }
+    }
+}
+
+impl Item for DiskIoItem {
+    fn name(&self) -> &str {
+        "diskio"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("diskio-label");
+
+        // Set initial text
+        update_label(&label, &self.backend, self.precision);
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, &self.backend, self.precision);
+        }
+    }
+}
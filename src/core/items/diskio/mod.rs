@@ -0,0 +1,11 @@
+// src/core/items/diskio/mod.rs
+//! Shows a block device's read and write throughput from
+//! `/proc/diskstats`.
+
+mod backend;
+mod item;
+
+pub use backend::{
+    DiskIoBackend, DiskStat, PROC_DISKSTATS_ENV, human_rate, parse_diskstats, throughput,
+};
+pub use item::{DiskIoConfig, DiskIoItem};
@@ -0,0 +1,120 @@
+// src/core/items/fan/backend.rs
+//
+// Reads fan speeds (RPM) from hwmon `fan*_input` files.
+
+use crate::core::utils::hwmon::{HwmonInput, InputKind, discover, hwmon_base, sensor_selected};
+use anyhow::{Result, bail};
+use std::path::Path;
+
+pub struct FanBackend {
+    fans: Vec<HwmonInput>,
+}
+
+impl FanBackend {
+    // Discover fans under the hwmon root (honours `SYS_HWMON_BASE`).
+    // `sensors` filters them by name or glob; empty or "all" keeps all.
+    pub fn new(sensors: &[String]) -> Result<Self> {
+        Self::with_base(&hwmon_base(), sensors)
+    }
+
+    // Like `new`, but discovering under an explicit hwmon root
+    pub fn with_base(base: &Path, sensors: &[String]) -> Result<Self> {
+        let available = discover(base, InputKind::Fan)?;
+        if available.is_empty() {
+            bail!("No fan sensors found in {base:?}");
+        }
+        let fans: Vec<_> = available
+            .iter()
+            .filter(|fan| sensor_selected(&fan.name, sensors))
+            .cloned()
+            .collect();
+        if fans.is_empty() {
+            let names: Vec<_> = available.iter().map(|fan| fan.name.as_str()).collect();
+            bail!(
+                "None of the fan sensors {sensors:?} exist; available: {}",
+                names.join(", ")
+            );
+        }
+        Ok(FanBackend { fans })
+    }
+
+    // Current speed of every selected fan, as `(name, rpm)`
+    pub fn read(&self) -> Result<Vec<(String, u64)>> {
+        self.fans
+            .iter()
+            .map(|fan| Ok((fan.name.clone(), fan.read_raw()?.max(0) as u64)))
+            .collect()
+    }
+}
+
+// Render readings as the label text, e.g. "1200rpm 900rpm"
+pub fn format_rpms(readings: &[(String, u64)]) -> String {
+    readings
+        .iter()
+        .map(|(_, rpm)| format!("{rpm}rpm"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// One line per fan for the details popover, e.g. "thinkpad-fan1: 1200rpm"
+pub fn format_rpm_lines(readings: &[(String, u64)]) -> Vec<String> {
+    readings
+        .iter()
+        .map(|(name, rpm)| format!("{name}: {rpm}rpm"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FanBackend, format_rpm_lines, format_rpms};
+    use std::fs;
+
+    #[test]
+    fn reads_and_formats_fan_speed() {
+        let base = std::env::temp_dir().join(format!("panel-rs-fan-{}", std::process::id()));
+        let chip = base.join("hwmon0");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "thinkpad\n").unwrap();
+        fs::write(chip.join("fan1_input"), "1200\n").unwrap();
+        fs::write(chip.join("fan2_input"), "900\n").unwrap();
+
+        let all = FanBackend::with_base(&base, &[]).unwrap();
+        let readings = all.read().unwrap();
+        assert_eq!(readings[0], ("thinkpad-fan1".to_string(), 1200));
+        assert_eq!(format_rpms(&readings), "1200rpm 900rpm");
+        assert_eq!(
+            format_rpm_lines(&readings),
+            ["thinkpad-fan1: 1200rpm", "thinkpad-fan2: 900rpm"]
+        );
+
+        let one = FanBackend::with_base(&base, &["thinkpad-fan1".into()]).unwrap();
+        assert_eq!(format_rpms(&one.read().unwrap()), "1200rpm");
+        let globbed = FanBackend::with_base(&base, &["*-fan2".into()]).unwrap();
+        assert_eq!(format_rpms(&globbed.read().unwrap()), "900rpm");
+
+        let err = FanBackend::with_base(&base, &["nope".into()])
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string()
+                .contains("available: thinkpad-fan1, thinkpad-fan2")
+        );
+
+        fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn missing_and_empty_roots_fail_differently() {
+        let base = std::env::temp_dir().join(format!("panel-rs-fan-empty-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+
+        let missing = FanBackend::with_base(&base, &[]).err().unwrap();
+        assert!(missing.to_string().contains("doesn't exist"));
+
+        fs::create_dir_all(&base).unwrap();
+        let empty = FanBackend::with_base(&base, &[]).err().unwrap();
+        assert!(empty.to_string().contains("No fan sensors found"));
+
+        fs::remove_dir_all(base).unwrap();
+    }
+}
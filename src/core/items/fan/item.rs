@@ -0,0 +1,154 @@
+// src/core/items/fan/item.rs
+//
+// A status-bar item displaying fan speeds,
+// updating every `refresh_secs` seconds.
+
+use super::backend::{FanBackend, format_rpm_lines, format_rpms};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::popover::attach_detail_popover;
+use crate::core::utils::status::{clear_error, show_error};
+use crate::core::utils::tolerant::{ErrorTolerant, default_max_consecutive_errors};
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for the `fan` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct FanConfig {
+    // Fans to show, as `<chip>-<label>` names or globs like
+    // `"thinkpad-*"`; empty or `"all"` shows all of them
+    #[serde(default)]
+    pub sensors: Vec<String>,
+    // How often (in seconds) to re-read the fans; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    // Failed reads in a row to ride out before showing N/A
+    #[serde(default = "default_max_consecutive_errors")]
+    pub max_consecutive_errors: u32,
+    // Open a list of every fan on click
+    #[serde(default)]
+    pub popover: bool,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for FanConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// FanItem shows the speed of one or more fans
+pub struct FanItem {
+    // Shared with the details popover
+    backend: Rc<FanBackend>,
+    popover: bool,
+    // Last good readings, kept through transient read errors
+    readings: RefCell<ErrorTolerant<Vec<(String, u64)>>>,
+    // How often to update the displayed speeds
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+}
+
+impl FanItem {
+    // Discover the configured fans. Fails when none are found, so the
+    // item is skipped on machines without readable fans.
+    pub fn new(cfg: &FanConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("fan")?;
+        let refresh =
+            item_refresh_interval("fan", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        Ok(Self {
+            backend: Rc::new(FanBackend::new(&cfg.sensors)?),
+            popover: cfg.popover,
+            readings: RefCell::new(ErrorTolerant::new(cfg.max_consecutive_errors)),
+            refresh,
+            label: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &FanConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Refresh the label from the backend
+fn update_label(
+    label: &Label,
+    backend: &FanBackend,
+    readings: &RefCell<ErrorTolerant<Vec<(String, u64)>>>,
+) {
+    let result = backend.read();
+    if let Err(e) = &result {
+        warn!(error = %e, "Failed to read fan speed");
+    }
+    match readings.borrow_mut().record(result) {
+        Ok(readings) => {
+            clear_error(label);
+            label.set_text(&format_rpms(readings));
+        }
+        Err(e) => {
+            show_error(label, "Fan N/A", &e);
+        }
+    }
+}
+
+impl Item for FanItem {
+    fn name(&self) -> &str {
+        "fan"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("fan-label");
+
+        // Set initial text
+        update_label(&label, &self.backend, &self.readings);
+        container.append(&label);
+
+        if self.popover {
+            let backend = self.backend.clone();
+            attach_detail_popover(&container, move || match backend.read() {
+                Ok(readings) => format_rpm_lines(&readings),
+                Err(e) => vec![format!("{e:#}")],
+            });
+        }
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, &self.backend, &self.readings);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+// src/core/items/fan/mod.rs
+//! A fan-speed item reading `fan*_input` from hwmon.
+
+mod backend;
+mod item;
+
+pub use backend::{FanBackend, format_rpms};
+pub use item::{FanConfig, FanItem};
@@ -0,0 +1,161 @@
+// src/core/items/gpu/backend.rs
+//
+// Reads GPU utilization (0-100%) from one of two sources:
+//
+// - NVIDIA: `nvidia-smi --query-gpu=utilization.gpu --format=csv,noheader,nounits`,
+//   which prints one line per GPU, e.g. `37`;
+// - AMD: `/sys/class/drm/card*/device/gpu_busy_percent`, a bare integer.
+
+use crate::core::utils::icon::{bucketed_name, percent_value};
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Overrides the DRM class root, mainly for tests
+pub const SYS_DRM_BASE_ENV: &str = "SYS_DRM_BASE";
+const SYS_DRM_BASE: &str = "/sys/class/drm";
+
+#[derive(Debug, Clone)]
+pub enum GpuBackend {
+    Nvidia,
+    // The first card's `gpu_busy_percent` file
+    Amdgpu(PathBuf),
+}
+
+impl GpuBackend {
+    pub fn nvidia() -> Self {
+        GpuBackend::Nvidia
+    }
+
+    // Find the first amdgpu card under the DRM root (honours
+    // `SYS_DRM_BASE`). Fails when there is none.
+    pub fn amdgpu() -> Result<Self> {
+        let base = std::env::var_os(SYS_DRM_BASE_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(SYS_DRM_BASE));
+        Ok(GpuBackend::Amdgpu(find_busy_percent(&base)?))
+    }
+
+    // Current utilization in percent. Blocks on `nvidia-smi`, so call
+    // it off the GTK main thread.
+    pub fn read(&self) -> Result<f64> {
+        match self {
+            GpuBackend::Nvidia => {
+                let output = Command::new("nvidia-smi")
+                    .args([
+                        "--query-gpu=utilization.gpu",
+                        "--format=csv,noheader,nounits",
+                    ])
+                    .output()
+                    .context("Running nvidia-smi")?;
+                if !output.status.success() {
+                    bail!("nvidia-smi exited with {}", output.status);
+                }
+                parse_nvidia_smi(&String::from_utf8_lossy(&output.stdout))
+            }
+            GpuBackend::Amdgpu(path) => {
+                let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+                parse_busy_percent(&raw)
+            }
+        }
+    }
+}
+
+// The first card (by name) exposing `device/gpu_busy_percent`
+fn find_busy_percent(base: &Path) -> Result<PathBuf> {
+    let entries = fs::read_dir(base).with_context(|| format!("Listing {base:?}"))?;
+    let mut cards: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            // `card0`, but not connectors like `card0-DP-1`
+            name.strip_prefix("card")
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .map(|entry| entry.path().join("device/gpu_busy_percent"))
+        .filter(|path| path.exists())
+        .collect();
+    cards.sort();
+    cards
+        .into_iter()
+        .next()
+        .with_context(|| format!("No amdgpu card with gpu_busy_percent in {base:?}"))
+}
+
+// The first GPU's utilization from nvidia-smi's CSV output
+pub fn parse_nvidia_smi(output: &str) -> Result<f64> {
+    let line = output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .context("nvidia-smi printed nothing")?;
+    line.parse()
+        .with_context(|| format!("Invalid utilization {line:?}"))
+}
+
+// A `gpu_busy_percent` file's value
+pub fn parse_busy_percent(raw: &str) -> Result<f64> {
+    raw.trim()
+        .parse::<u8>()
+        .map(f64::from)
+        .with_context(|| format!("Invalid gpu_busy_percent {:?}", raw.trim()))
+}
+
+// Load icons by busy percentage
+const ICONS: [(u8, &str); 3] = [
+    (33, "gpu-low-symbolic"),
+    (66, "gpu-medium-symbolic"),
+    (u8::MAX, "gpu-high-symbolic"),
+];
+
+// Icon names for a load, best first. Few themes ship GPU icons, so
+// the generic display icon backs them up.
+pub fn icon_for(percent: f64) -> [&'static str; 2] {
+    [
+        bucketed_name(percent_value(percent), &ICONS, None),
+        "video-display-symbolic",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_busy_percent, icon_for, parse_busy_percent, parse_nvidia_smi};
+    use std::fs;
+
+    #[test]
+    fn parses_nvidia_smi_lines() {
+        assert_eq!(parse_nvidia_smi("37\n").unwrap(), 37.0);
+        // One line per GPU: the first wins
+        assert_eq!(parse_nvidia_smi("\n 5\n90\n").unwrap(), 5.0);
+        assert!(parse_nvidia_smi("").is_err());
+        assert!(parse_nvidia_smi("[N/A]\n").is_err());
+    }
+
+    #[test]
+    fn parses_busy_percent() {
+        assert_eq!(parse_busy_percent("12\n").unwrap(), 12.0);
+        assert_eq!(parse_busy_percent("100").unwrap(), 100.0);
+        assert!(parse_busy_percent("-1").is_err());
+        assert_eq!(icon_for(80.0)[0], "gpu-high-symbolic");
+    }
+
+    #[test]
+    fn finds_the_first_amdgpu_card() {
+        let base = std::env::temp_dir().join(format!("panel-rs-drm-{}", std::process::id()));
+        for card in ["card1", "card0-DP-1", "renderD128"] {
+            fs::create_dir_all(base.join(card).join("device")).unwrap();
+        }
+        fs::write(base.join("card0-DP-1/device/gpu_busy_percent"), "1\n").unwrap();
+        assert!(find_busy_percent(&base).is_err());
+
+        fs::write(base.join("card1/device/gpu_busy_percent"), "42\n").unwrap();
+        assert_eq!(
+            find_busy_percent(&base).unwrap(),
+            base.join("card1/device/gpu_busy_percent")
+        );
+
+        fs::remove_dir_all(base).unwrap();
+    }
+}
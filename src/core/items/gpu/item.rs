@@ -0,0 +1,157 @@
+// src/core/items/gpu/item.rs
+//
+// A status-bar item displaying GPU utilization,
+// updating every `refresh_secs` seconds.
+//
+// `nvidia-smi` takes tens of milliseconds to answer, so reads happen on
+// a worker thread.
+
+use super::backend::{GpuBackend, icon_for};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Image, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Where utilization comes from
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    #[default]
+    Nvidia,
+    Amdgpu,
+}
+
+// Settings for the `gpu` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct GpuConfig {
+    #[serde(default)]
+    pub backend: Backend,
+    // How often (in seconds) to re-read; defaults to the global
+    // `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for GpuConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// GpuItem shows a load icon and the utilization percentage
+pub struct GpuItem {
+    backend: GpuBackend,
+    // How often to re-read
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+    // A read is still in flight; skip ticks until it finishes
+    busy: Rc<Cell<bool>>,
+}
+
+impl GpuItem {
+    // Fails without an amdgpu card, so the item is skipped
+    pub fn new(cfg: &GpuConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("gpu")?;
+        let refresh =
+            item_refresh_interval("gpu", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        let backend = match cfg.backend {
+            Backend::Nvidia => GpuBackend::nvidia(),
+            Backend::Amdgpu => GpuBackend::amdgpu()?,
+        };
+
+        Ok(Self {
+            backend,
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            busy: Rc::new(Cell::new(false)),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &GpuConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+impl Item for GpuItem {
+    fn name(&self) -> &str {
+        "gpu"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("gpu-label");
+        layout::set_icon(&image, &icon_for(0.0));
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Read right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        let Some((image, label)) = self.widgets.borrow().clone() else {
+            return;
+        };
+        if self.busy.replace(true) {
+            return;
+        }
+
+        let backend = self.backend.clone();
+        let busy = self.busy.clone();
+        glib::spawn_future_local(async move {
+            let result = gio::spawn_blocking(move || backend.read())
+                .await
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("read thread panicked")));
+
+            match result {
+                Ok(percent) => {
+                    clear_error(&label);
+                    layout::set_icon(&image, &icon_for(percent));
+                    label.set_text(&format!("{percent:.0}%"));
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to read GPU utilization");
+                    show_error(&label, "GPU N/A", &e);
+                }
+            }
+            busy.set(false);
+        });
+    }
+}
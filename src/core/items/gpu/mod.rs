@@ -0,0 +1,8 @@
+// src/core/items/gpu/mod.rs
+//! Shows GPU utilization from `nvidia-smi` or amdgpu's sysfs counter.
+
+mod backend;
+mod item;
+
+pub use backend::{GpuBackend, SYS_DRM_BASE_ENV, icon_for, parse_busy_percent, parse_nvidia_smi};
+pub use item::{Backend, GpuConfig, GpuItem};
@@ -0,0 +1,185 @@
+// src/core/items/idle_inhibitor.rs
+//
+// A status-bar item toggling a systemd-logind idle inhibitor.
+//
+// Clicking takes an `idle` lock through `org.freedesktop.login1.Manager.Inhibit`
+// and keeps the returned file descriptor open; clicking again closes it,
+// which releases the lock. Clicks while an `Inhibit` call is still pending
+// are ignored, so a double click can't take a second lock. Handy during
+// presentations.
+
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Context, Result};
+use gtk4::gio::{self, BusType, DBusCallFlags, DBusProxy, DBusProxyFlags, UnixFDList};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Image, Widget};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::os::fd::{FromRawFd, OwnedFd};
+use std::rc::Rc;
+use tracing::{info, warn};
+
+// Preferred icon first, then stock theme names as fallbacks
+const ICON_ACTIVE: &[&str] = &[
+    "idle-inhibitor-active",
+    "changes-prevent-symbolic",
+    "changes-prevent",
+];
+const ICON_INACTIVE: &[&str] = &[
+    "idle-inhibitor-inactive",
+    "changes-allow-symbolic",
+    "changes-allow",
+];
+
+// Settings for the `idle_inhibitor` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct IdleInhibitorConfig {
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for IdleInhibitorConfig {}
+
+// IdleInhibitorItem shows whether idling is inhibited and toggles it on click
+pub struct IdleInhibitorItem {
+    // logind's Manager interface on the system bus
+    proxy: DBusProxy,
+    // Shared with the click handler and pending `Inhibit` calls
+    state: Rc<InhibitState>,
+    // Lazily initialize the GTK Image we switch on each toggle.
+    icon: RefCell<Option<Image>>,
+}
+
+impl IdleInhibitorItem {
+    // Connect to logind. Fails without a system bus, so the item is skipped.
+    pub fn new(_cfg: &IdleInhibitorConfig) -> Result<Self> {
+        let proxy = DBusProxy::for_bus_sync(
+            BusType::System,
+            DBusProxyFlags::DO_NOT_LOAD_PROPERTIES | DBusProxyFlags::DO_NOT_CONNECT_SIGNALS,
+            None,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+            gio::Cancellable::NONE,
+        )
+        .context("Connecting to systemd-logind")?;
+
+        Ok(Self {
+            proxy,
+            state: Rc::new(InhibitState::default()),
+            icon: RefCell::new(None),
+        })
+    }
+}
+
+// The lock, and whether a request for one is still in flight
+#[derive(Default)]
+struct InhibitState {
+    // The inhibitor lock; idling is blocked while this is open
+    lock: RefCell<Option<OwnedFd>>,
+    // An `Inhibit` call hasn't answered yet; clicks are ignored meanwhile
+    pending: Cell<bool>,
+}
+
+// Reflect the lock state on the icon and CSS classes
+fn show_state(icon: &Image, active: bool) {
+    if active {
+        layout::set_icon(icon, ICON_ACTIVE);
+        icon.remove_css_class("idle-inhibitor-inactive");
+        icon.add_css_class("idle-inhibitor-active");
+        icon.set_tooltip_text(Some("Idle inhibited"));
+    } else {
+        layout::set_icon(icon, ICON_INACTIVE);
+        icon.remove_css_class("idle-inhibitor-active");
+        icon.add_css_class("idle-inhibitor-inactive");
+        icon.set_tooltip_text(Some("Idle allowed"));
+    }
+}
+
+// Release the lock if held, otherwise ask logind for a new one
+fn toggle(proxy: &DBusProxy, state: &Rc<InhibitState>, icon: &Image) {
+    if state.pending.get() {
+        return;
+    }
+    // Dropping the descriptor is all it takes to release the inhibitor
+    if state.lock.borrow_mut().take().is_some() {
+        info!("Released idle inhibitor");
+        show_state(icon, false);
+        return;
+    }
+
+    let args = ("idle", "panel-rs", "Inhibited from the panel", "block").to_variant();
+    state.pending.set(true);
+    let state = state.clone();
+    let icon = icon.clone();
+    proxy.call_with_unix_fd_list(
+        "Inhibit",
+        Some(&args),
+        DBusCallFlags::NONE,
+        -1,
+        UnixFDList::NONE,
+        gio::Cancellable::NONE,
+        move |result| {
+            state.pending.set(false);
+            match result.map_err(anyhow::Error::from).and_then(take_fd) {
+                Ok(fd) => {
+                    info!("Took idle inhibitor");
+                    *state.lock.borrow_mut() = Some(fd);
+                    show_state(&icon, true);
+                }
+                Err(e) => warn!(error = %e, "Failed to inhibit idle"),
+            }
+        },
+    );
+}
+
+// Extract the inhibitor descriptor from an `Inhibit` reply (`(h)`)
+fn take_fd((reply, fds): (glib::Variant, Option<UnixFDList>)) -> Result<OwnedFd> {
+    let handle = reply
+        .try_child_value(0)
+        .and_then(|v| v.get::<glib::variant::Handle>())
+        .context("Inhibit reply has no file descriptor handle")?;
+    let fds = fds.context("Inhibit reply carries no file descriptors")?;
+    let raw = fds.get(handle.0)?;
+    // SAFETY: g_unix_fd_list_get returns a fresh duplicate that we own
+    Ok(unsafe { OwnedFd::from_raw_fd(raw) })
+}
+
+impl Item for IdleInhibitorItem {
+    fn name(&self) -> &str {
+        "idle_inhibitor"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon
+        let icon = {
+            let mut slot = self.icon.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(layout::icon());
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        show_state(&icon, self.state.lock.borrow().is_some());
+
+        // Left click toggles the inhibitor
+        let gesture = GestureClick::new();
+        let proxy = self.proxy.clone();
+        let state = self.state.clone();
+        let clicked_icon = icon.clone();
+        gesture.connect_released(move |_, _, _, _| toggle(&proxy, &state, &clicked_icon));
+        container.add_controller(gesture);
+
+        container.append(&icon);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Nothing to poll: the state only changes when clicked
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+// src/core/items/lockkeys.rs
+//
+// A status-bar item showing Caps Lock and Num Lock,
+// updating every `refresh_secs` seconds.
+//
+// Wayland has no portable way to query modifier state from a client, so
+// this reads the keyboard LEDs the kernel exposes as
+// `/sys/class/leds/input*::capslock` and `input*::numlock`. A lock key
+// without an LED is left out, and the item is skipped when neither has one.
+
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+// Overrides the LED class root, mainly for tests
+pub const SYS_LEDS_BASE_ENV: &str = "SYS_LEDS_BASE";
+const SYS_LEDS_BASE: &str = "/sys/class/leds";
+
+// Settings for the `lockkeys` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct LockKeysConfig {
+    // How often (in seconds) to re-read the LEDs; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for LockKeysConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// LockKeysItem shows an `A` for Caps Lock and a `1` for Num Lock
+pub struct LockKeysItem {
+    base: PathBuf,
+    // Which LEDs exist, checked once at startup
+    has_caps: bool,
+    has_num: bool,
+    // How often to re-read the LEDs
+    refresh: Duration,
+    // Lazily initialized (caps, num) labels
+    labels: RefCell<Option<(Label, Label)>>,
+}
+
+impl LockKeysItem {
+    pub fn new(cfg: &LockKeysConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("lockkeys")?;
+        let refresh =
+            item_refresh_interval("lockkeys", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        let base = std::env::var_os(SYS_LEDS_BASE_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(SYS_LEDS_BASE));
+        let has_caps = has_led(&base, "capslock")?;
+        let has_num = has_led(&base, "numlock")?;
+        if !has_caps && !has_num {
+            bail!("No capslock or numlock LED found in {base:?}");
+        }
+
+        Ok(Self {
+            base,
+            has_caps,
+            has_num,
+            refresh,
+            labels: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &LockKeysConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+
+    // Refresh the labels whose LEDs exist; the others stay hidden
+    fn update_labels(&self, caps: &Label, num: &Label) {
+        if self.has_caps {
+            show_led(caps, &self.base, "capslock");
+        }
+        if self.has_num {
+            show_led(num, &self.base, "numlock");
+        }
+    }
+}
+
+// Parse an LED `brightness` file: any non-zero value means lit
+pub fn parse_brightness(raw: &str) -> Result<bool> {
+    let value: u32 = raw
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid LED brightness {:?}", raw.trim()))?;
+    Ok(value > 0)
+}
+
+// Whether any keyboard has an `<input>::<led>` LED under `base`
+pub fn has_led(base: &Path, led: &str) -> Result<bool> {
+    let suffix = format!("::{led}");
+    let entries = fs::read_dir(base).with_context(|| format!("Listing LEDs in {base:?}"))?;
+    Ok(entries
+        .flatten()
+        .any(|entry| entry.file_name().to_string_lossy().ends_with(&suffix)))
+}
+
+// Whether any keyboard's `<input>::<led>` LED under `base` is lit.
+// With several keyboards attached the kernel keeps them in sync, so one
+// lit LED is enough.
+pub fn led_state(base: &Path, led: &str) -> Result<bool> {
+    let suffix = format!("::{led}");
+    let entries = fs::read_dir(base).with_context(|| format!("Listing LEDs in {base:?}"))?;
+
+    let mut found = false;
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        if !name.to_string_lossy().ends_with(&suffix) {
+            continue;
+        }
+        found = true;
+        let raw = fs::read_to_string(entry.path().join("brightness"))
+            .with_context(|| format!("Reading {:?} brightness", entry.path()))?;
+        if parse_brightness(&raw)? {
+            return Ok(true);
+        }
+    }
+
+    if !found {
+        bail!("No {led} LED found in {base:?}");
+    }
+    Ok(false)
+}
+
+// Brighten or dim a label via the `lock-on` / `lock-off` CSS classes
+fn show_led(label: &Label, base: &Path, led: &str) {
+    let on = match led_state(base, led) {
+        Ok(on) => on,
+        Err(e) => {
+            warn!(led, error = %e, "Failed to read lock key state");
+            false
+        }
+    };
+    if on {
+        label.remove_css_class("lock-off");
+        label.add_css_class("lock-on");
+    } else {
+        label.remove_css_class("lock-on");
+        label.add_css_class("lock-off");
+    }
+}
+
+impl Item for LockKeysItem {
+    fn name(&self) -> &str {
+        "lockkeys"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+        container.add_css_class("lockkeys");
+
+        // Lazily initialize the labels
+        let (caps, num) = {
+            let mut slot = self.labels.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((Label::new(Some("A")), Label::new(Some("1"))));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+
+        // Set initial state
+        caps.set_visible(self.has_caps);
+        num.set_visible(self.has_num);
+        self.update_labels(&caps, &num);
+        container.append(&caps);
+        container.append(&num);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some((caps, num)) = self.labels.borrow().as_ref() {
+            self.update_labels(caps, num);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_led, led_state, parse_brightness};
+    use std::fs;
+
+    #[test]
+    fn parses_brightness_values() {
+        assert!(!parse_brightness("0\n").unwrap());
+        assert!(parse_brightness("1\n").unwrap());
+        assert!(parse_brightness("255").unwrap());
+        assert!(parse_brightness("on").is_err());
+    }
+
+    #[test]
+    fn reads_led_state_from_sysfs() {
+        let base = std::env::temp_dir().join(format!("panel-rs-leds-{}", std::process::id()));
+        for (led, brightness) in [
+            ("input3::capslock", "1\n"),
+            ("input3::numlock", "0\n"),
+            ("input3::scrolllock", "1\n"),
+        ] {
+            fs::create_dir_all(base.join(led)).unwrap();
+            fs::write(base.join(led).join("brightness"), brightness).unwrap();
+        }
+
+        assert!(led_state(&base, "capslock").unwrap());
+        assert!(!led_state(&base, "numlock").unwrap());
+        assert!(led_state(&base, "kana").is_err());
+        assert!(has_led(&base, "numlock").unwrap());
+        assert!(!has_led(&base, "kana").unwrap());
+        assert!(has_led(&base.join("missing"), "numlock").is_err());
+
+        fs::remove_dir_all(base).unwrap();
+    }
+}
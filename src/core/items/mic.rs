@@ -0,0 +1,222 @@
+// src/core/items/mic.rs
+//
+// A status-bar item showing the default microphone's mute state and
+// level, updating every `refresh_secs` seconds. Left click toggles mute.
+//
+// Works with PulseAudio and PipeWire alike through `pactl`, which runs
+// on a worker thread.
+
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::icon::bucketed_name;
+use crate::core::utils::pactl::{pactl, parse_mute, parse_volume};
+use crate::core::utils::reads::ReadGate;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Image, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+const SOURCE: &str = "@DEFAULT_SOURCE@";
+
+// Settings for the `mic` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MicConfig {
+    // How often (in seconds) to re-read the source; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for MicConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// MicItem shows a microphone icon and the input level
+pub struct MicItem {
+    // How often to re-read the source
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+    // One read in flight at a time; ticks skip, toggles queue
+    reads: Rc<ReadGate>,
+}
+
+impl MicItem {
+    pub fn new(cfg: &MicConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("mic")?;
+        let refresh =
+            item_refresh_interval("mic", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        Ok(Self {
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            reads: Rc::new(ReadGate::default()),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &MicConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+const MUTED_ICON: &str = "microphone-sensitivity-muted-symbolic";
+
+// Sensitivity icons by volume percentage; silence looks muted
+const ICONS: [(u8, &str); 4] = [
+    (1, MUTED_ICON),
+    (34, "microphone-sensitivity-low-symbolic"),
+    (67, "microphone-sensitivity-medium-symbolic"),
+    (u8::MAX, "microphone-sensitivity-high-symbolic"),
+];
+
+// Symbolic icon for a source's state
+pub fn icon_for(muted: bool, volume: u32) -> &'static str {
+    let volume = volume.min(100) as u8;
+    bucketed_name(volume, &ICONS, muted.then_some(MUTED_ICON))
+}
+
+// (muted, volume %) of the default source. Runs on a worker thread.
+fn read_source() -> Result<(bool, u32)> {
+    let muted = parse_mute(&pactl(&["get-source-mute", SOURCE])?)?;
+    let volume = parse_volume(&pactl(&["get-source-volume", SOURCE])?)?;
+    Ok((muted, volume))
+}
+
+// Read the source for a tick, unless a read is already in flight
+fn refresh(image: &Image, label: &Label, reads: &Rc<ReadGate>) {
+    if reads.start() {
+        read(image, label, reads);
+    }
+}
+
+// Read the source after a toggle, queued behind any read in flight
+fn refresh_after_toggle(image: &Image, label: &Label, reads: &Rc<ReadGate>) {
+    if reads.start_or_queue() {
+        read(image, label, reads);
+    }
+}
+
+// Read the source off the main thread and show it
+fn read(image: &Image, label: &Label, reads: &Rc<ReadGate>) {
+    let (image, label, reads) = (image.clone(), label.clone(), reads.clone());
+    glib::spawn_future_local(async move {
+        let result = gio::spawn_blocking(read_source)
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("read thread panicked")));
+
+        match result {
+            Ok((muted, volume)) => {
+                clear_error(&label);
+                layout::set_icon(&image, &[icon_for(muted, volume)]);
+                label.set_text(&format!("{volume}%"));
+                if muted {
+                    label.add_css_class("muted");
+                } else {
+                    label.remove_css_class("muted");
+                }
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to read microphone state");
+                show_error(&label, "Mic N/A", &e);
+            }
+        }
+        if reads.finish() {
+            read(&image, &label, &reads);
+        }
+    });
+}
+
+impl Item for MicItem {
+    fn name(&self) -> &str {
+        "mic"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("mic-label");
+        layout::set_icon(&image, &[icon_for(true, 0)]);
+
+        // Left click toggles mute, then re-reads once pactl is done so
+        // the icon follows
+        let gesture = GestureClick::new();
+        let (clicked_image, clicked_label) = (image.clone(), label.clone());
+        let reads = self.reads.clone();
+        gesture.connect_released(move |_, _, _, _| {
+            let (image, label, reads) =
+                (clicked_image.clone(), clicked_label.clone(), reads.clone());
+            glib::spawn_future_local(async move {
+                let toggled = gio::spawn_blocking(|| pactl(&["set-source-mute", SOURCE, "toggle"]))
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("toggle thread panicked")));
+                if let Err(e) = toggled {
+                    warn!(error = %e, "Failed to toggle microphone mute");
+                }
+                refresh_after_toggle(&image, &label, &reads);
+            });
+        });
+        container.add_controller(gesture);
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Read right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some((image, label)) = self.widgets.borrow().as_ref() {
+            refresh(image, label, &self.reads);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::icon_for;
+
+    #[test]
+    fn icons_follow_mute_and_level() {
+        assert_eq!(icon_for(true, 80), "microphone-sensitivity-muted-symbolic");
+        assert_eq!(icon_for(false, 0), "microphone-sensitivity-muted-symbolic");
+        assert_eq!(icon_for(false, 20), "microphone-sensitivity-low-symbolic");
+        assert_eq!(
+            icon_for(false, 50),
+            "microphone-sensitivity-medium-symbolic"
+        );
+        assert_eq!(icon_for(false, 150), "microphone-sensitivity-high-symbolic");
+    }
+}
@@ -1,4 +1,30 @@
 // src/core/items/mod.rs
 //! A collection of status-bar item implementations.
 
+pub mod bluetooth;
+pub mod button;
 pub mod clock;
+pub mod custom;
+pub mod diskio;
+pub mod fan;
+pub mod gpu;
+pub mod idle_inhibitor;
+pub mod lockkeys;
+pub mod mic;
+pub mod mode;
+pub mod netinfo;
+pub mod nightlight;
+pub mod notifications;
+pub mod power_menu;
+pub mod power_profile;
+pub mod procs;
+pub mod psi;
+pub mod relative;
+pub mod spacer;
+pub mod sysinfo;
+pub mod text;
+pub mod timer;
+pub mod vpn;
+pub mod weather;
+pub mod wifi;
+pub mod workspaces;
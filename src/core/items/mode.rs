@@ -0,0 +1,168 @@
+// src/core/items/mode.rs
+//
+// A status-bar item showing the compositor's binding mode (Sway) or
+// submap (Hyprland), e.g. "resize", and hidden in the default mode.
+// Event driven over the same IPC as the workspaces item.
+
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::items::workspaces::BackendKind;
+use crate::core::items::workspaces::hyprland::{Hyprland, HyprlandEvents};
+use crate::core::items::workspaces::sway::{
+    self, GET_BINDING_STATE, SwayEvents, SwayIpc, parse_binding_state, parse_mode_event,
+};
+use anyhow::{Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use tracing::warn;
+
+// Settings for the `mode` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ModeConfig {
+    #[serde(default)]
+    pub backend: BackendKind,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for ModeConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        if self.backend == BackendKind::River {
+            bail!("{name}: the river backend isn't implemented yet");
+        }
+        Ok(())
+    }
+}
+
+pub struct ModeItem {
+    backend: BackendKind,
+    // Lazily initialized label
+    label: RefCell<Option<Label>>,
+    // Bumped by start() and stop(); a listener exits once it's stale
+    generation: Rc<Cell<u64>>,
+}
+
+// A subscribed connection that reports mode changes
+enum ModeEvents {
+    Sway(SwayEvents),
+    Hyprland(HyprlandEvents),
+}
+
+impl ModeEvents {
+    // Subscribe, returning the current mode if the compositor can tell
+    fn connect(backend: BackendKind) -> Result<(Option<String>, Self)> {
+        match backend {
+            BackendKind::Sway => {
+                let ipc = SwayIpc::from_env()?;
+                let events = sway::subscribe(&ipc, &["mode"])?;
+                let current = parse_binding_state(&ipc.request(GET_BINDING_STATE, "")?)?;
+                Ok((Some(current), ModeEvents::Sway(events)))
+            }
+            // Hyprland has no query for the submap; assume the default
+            BackendKind::Hyprland => Ok((
+                None,
+                ModeEvents::Hyprland(Hyprland::from_env()?.connect_events()?),
+            )),
+            BackendKind::River => bail!("mode: the river backend isn't implemented yet"),
+        }
+    }
+
+    // Block until the mode changes and return the new one
+    fn next_mode(&mut self) -> Result<String> {
+        match self {
+            ModeEvents::Sway(events) => parse_mode_event(&events.next_message()?.1),
+            ModeEvents::Hyprland(events) => loop {
+                let (name, data) = events.next_line()?;
+                if name == "submap" {
+                    return Ok(data);
+                }
+            },
+        }
+    }
+}
+
+impl ModeItem {
+    pub fn new(cfg: &ModeConfig) -> Result<Self> {
+        cfg.validate("mode")?;
+        Ok(Self {
+            backend: cfg.backend,
+            label: RefCell::new(None),
+            generation: Rc::new(Cell::new(0)),
+        })
+    }
+}
+
+// Show `mode`, or hide the label in the default mode. Hyprland reports
+// leaving a submap as an empty name.
+fn show_mode(label: &Label, mode: &str) {
+    let default = mode.is_empty() || mode == "default";
+    label.set_text(mode);
+    label.set_visible(!default);
+}
+
+async fn listen(label: Label, mut events: ModeEvents, generation: Rc<Cell<u64>>, current: u64) {
+    loop {
+        let Ok((returned, next)) = gio::spawn_blocking(move || {
+            let next = events.next_mode();
+            (events, next)
+        })
+        .await
+        else {
+            warn!("Mode event thread panicked");
+            return;
+        };
+        if generation.get() != current {
+            return;
+        }
+        match next {
+            Ok(mode) => show_mode(&label, &mode),
+            Err(e) => {
+                warn!(error = %e, "Mode event stream ended");
+                return;
+            }
+        }
+        events = returned;
+    }
+}
+
+impl Item for ModeItem {
+    fn name(&self) -> &str {
+        "mode"
+    }
+
+    fn widget(&self) -> Widget {
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("mode-label");
+        show_mode(&label, "default");
+        label.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        let Some(label) = self.label.borrow().clone() else {
+            return Ok(());
+        };
+        let (mode, events) = ModeEvents::connect(self.backend)?;
+        if let Some(mode) = mode {
+            show_mode(&label, &mode);
+        }
+
+        let current = self.generation.get() + 1;
+        self.generation.set(current);
+        glib::spawn_future_local(listen(label, events, self.generation.clone(), current));
+        Ok(())
+    }
+
+    fn stop(&self) {
+        // The listener notices after its next event
+        self.generation.set(self.generation.get() + 1);
+    }
+}
@@ -0,0 +1,92 @@
+// src/core/items/netinfo/address.rs
+//
+// Picking addresses out of `ip -o addr show` and public-IP responses.
+
+use anyhow::{Context, Result, bail};
+use std::net::IpAddr;
+
+// The address to show from `ip -o addr show scope global` output, which
+// has one line per address:
+//
+//   2: wlp3s0    inet 192.168.1.23/24 brd 192.168.1.255 scope global dynamic wlp3s0\ ..
+//
+// Only `interface`'s addresses count when set. IPv4 wins over IPv6.
+pub fn parse_ip_addr(output: &str, interface: Option<&str>) -> Option<String> {
+    let mut v6 = None;
+    for line in output.lines() {
+        let mut fields = line.split_whitespace().skip(1); // index
+        let Some(name) = fields.next() else {
+            continue;
+        };
+        if interface.is_some_and(|wanted| wanted != name) {
+            continue;
+        }
+        let (Some(family), Some(cidr)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let address = cidr.split('/').next().unwrap_or(cidr).to_string();
+        match family {
+            "inet" => return Some(address),
+            "inet6" if v6.is_none() => v6 = Some(address),
+            _ => {}
+        }
+    }
+    v6
+}
+
+// Validate a public-IP endpoint's reply, which should be a bare address
+pub fn parse_public_ip(body: &str) -> Result<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        bail!("Empty reply from public IP endpoint");
+    }
+    let ip: IpAddr = body
+        .parse()
+        .with_context(|| format!("Not an IP address: {:?}", truncate(body)))?;
+    Ok(ip.to_string())
+}
+
+// The start of an unexpected reply, for error messages
+fn truncate(body: &str) -> &str {
+    match body.char_indices().nth(40) {
+        Some((i, _)) => &body[..i],
+        None => body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_ip_addr, parse_public_ip};
+
+    const IP_ADDR: &str = "\
+2: wlp3s0    inet6 2a01:db8::1234/64 scope global dynamic mngtmpaddr \\       valid_lft 86300sec preferred_lft 14300sec
+2: wlp3s0    inet 192.168.1.23/24 brd 192.168.1.255 scope global dynamic noprefixroute wlp3s0\\       valid_lft 85915sec preferred_lft 85915sec
+4: tun0    inet6 fd00::2/64 scope global \\       valid_lft forever preferred_lft forever
+";
+
+    #[test]
+    fn prefers_ipv4_of_the_chosen_interface() {
+        assert_eq!(
+            parse_ip_addr(IP_ADDR, None).as_deref(),
+            Some("192.168.1.23")
+        );
+        assert_eq!(
+            parse_ip_addr(IP_ADDR, Some("wlp3s0")).as_deref(),
+            Some("192.168.1.23")
+        );
+        assert_eq!(
+            parse_ip_addr(IP_ADDR, Some("tun0")).as_deref(),
+            Some("fd00::2")
+        );
+        assert_eq!(parse_ip_addr(IP_ADDR, Some("eth0")), None);
+        assert_eq!(parse_ip_addr("", None), None);
+    }
+
+    #[test]
+    fn validates_public_ip_replies() {
+        assert_eq!(parse_public_ip("203.0.113.7\n").unwrap(), "203.0.113.7");
+        assert_eq!(parse_public_ip("2001:db8::1").unwrap(), "2001:db8::1");
+        assert!(parse_public_ip("").is_err());
+        assert!(parse_public_ip("<html>rate limited</html>").is_err());
+    }
+}
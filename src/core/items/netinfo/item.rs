@@ -0,0 +1,210 @@
+// src/core/items/netinfo/item.rs
+//
+// A status-bar item showing an IP address, updating every
+// `refresh_secs` seconds.
+//
+// Both lookups run a command (`ip` or `curl`), so they happen on a
+// worker thread to keep the GTK main loop responsive.
+
+use super::address::{parse_ip_addr, parse_public_ip};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::http::http_get;
+use crate::core::utils::status::{clear_error, show_error};
+use crate::core::utils::tolerant::ErrorTolerant;
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::process::Command;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Which address to show
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    // The address of a local interface
+    #[default]
+    Local,
+    // The address the internet sees, from `endpoint`
+    Public,
+}
+
+// Settings for the `netinfo` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetInfoConfig {
+    #[serde(default)]
+    pub mode: Mode,
+    // Interface for `local` mode, e.g. "wlan0"; defaults to any
+    pub interface: Option<String>,
+    // URL answering with a bare IP address, for `public` mode
+    #[serde(default = "default_endpoint")]
+    pub endpoint: String,
+    // How often (in seconds) to look up the address; defaults to the
+    // global `refresh_secs` locally and to 300 for public lookups
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for NetInfoConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+fn default_endpoint() -> String {
+    "https://api.ipify.org".to_string()
+}
+
+// Public lookups hit someone else's server; don't poll it every second
+const DEFAULT_PUBLIC_REFRESH_SECS: f64 = 300.0;
+const FETCH_TIMEOUT_SECS: u32 = 10;
+
+// NetInfoItem shows one IP address
+pub struct NetInfoItem {
+    mode: Mode,
+    interface: Option<String>,
+    endpoint: String,
+    // How often to look up the address
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each lookup.
+    label: RefCell<Option<Label>>,
+    // Shared with in-flight lookups
+    state: Rc<LookupState>,
+}
+
+// State shared between scheduler ticks and in-flight lookups
+struct LookupState {
+    // A lookup is still in flight; skip ticks until it finishes
+    busy: Cell<bool>,
+    // Last address found, kept while lookups fail
+    address: RefCell<ErrorTolerant<String>>,
+}
+
+impl NetInfoItem {
+    pub fn new(cfg: &NetInfoConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("netinfo")?;
+        let refresh = item_refresh_interval(
+            "netinfo",
+            cfg.refresh_secs.unwrap_or(match cfg.mode {
+                Mode::Local => default_refresh_secs,
+                Mode::Public => DEFAULT_PUBLIC_REFRESH_SECS,
+            }),
+        )?;
+
+        Ok(Self {
+            mode: cfg.mode,
+            interface: cfg.interface.clone(),
+            endpoint: cfg.endpoint.clone(),
+            refresh,
+            label: RefCell::new(None),
+            state: Rc::new(LookupState {
+                busy: Cell::new(false),
+                // An address is only ever stale, never wrong enough for N/A
+                address: RefCell::new(ErrorTolerant::new(u32::MAX)),
+            }),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &NetInfoConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// The first global address, per `ip`. Runs on a worker thread.
+fn local_address(interface: Option<&str>) -> Result<String> {
+    let output = Command::new("ip")
+        .args(["-o", "addr", "show", "scope", "global"])
+        .output()
+        .context("Running ip")?;
+    if !output.status.success() {
+        bail!("ip exited with {}", output.status);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match (parse_ip_addr(&stdout, interface), interface) {
+        (Some(address), _) => Ok(address),
+        (None, Some(name)) => bail!("No address on {name}"),
+        (None, None) => bail!("No network address"),
+    }
+}
+
+// The public address, per `endpoint`. Runs on a worker thread.
+fn public_address(endpoint: &str) -> Result<String> {
+    parse_public_ip(&http_get(endpoint, FETCH_TIMEOUT_SECS)?)
+}
+
+impl Item for NetInfoItem {
+    fn name(&self) -> &str {
+        "netinfo"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("netinfo-label");
+
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Look up right away rather than waiting a whole interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        let Some(label) = self.label.borrow().clone() else {
+            return;
+        };
+        if self.state.busy.replace(true) {
+            return;
+        }
+
+        let mode = self.mode;
+        let interface = self.interface.clone();
+        let endpoint = self.endpoint.clone();
+        let state = self.state.clone();
+        glib::spawn_future_local(async move {
+            let result = gio::spawn_blocking(move || match mode {
+                Mode::Local => local_address(interface.as_deref()),
+                Mode::Public => public_address(&endpoint),
+            })
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("lookup thread panicked")));
+
+            if let Err(e) = &result {
+                warn!(error = %e, "Failed to look up IP address");
+            }
+            match state.address.borrow_mut().record(result) {
+                Ok(address) => {
+                    clear_error(&label);
+                    label.set_text(address);
+                }
+                Err(e) => show_error(&label, "IP N/A", &e),
+            }
+            state.busy.set(false);
+        });
+    }
+}
@@ -0,0 +1,9 @@
+// src/core/items/netinfo/mod.rs
+//! Shows this machine's local IP address on an interface, or its public
+//! IP as seen by a web service.
+
+mod address;
+mod item;
+
+pub use address::{parse_ip_addr, parse_public_ip};
+pub use item::{Mode, NetInfoConfig, NetInfoItem};
@@ -0,0 +1,316 @@
+// src/core/items/nightlight.rs
+//
+// A status-bar item toggling a redshift night light. Clicking sets the
+// screen to `on_temp` (`redshift -P -O`) or back to neutral
+// (`redshift -x`); the tooltip shows what redshift's own schedule would
+// apply right now, refreshed every `refresh_secs` seconds.
+
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Image, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::process::Command;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+const ICON_ON: &[&str] = &["night-light-symbolic", "weather-clear-night-symbolic"];
+const ICON_OFF: &[&str] = &["weather-clear-symbolic"];
+
+// Settings for the `nightlight` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct NightLightConfig {
+    // Colour temperature (Kelvin) while the night light is on
+    #[serde(default = "default_on_temp")]
+    pub on_temp: u32,
+    // How often (in seconds) to re-query redshift; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for NightLightConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)?;
+        // redshift's accepted range
+        if !(1000..=25000).contains(&self.on_temp) {
+            bail!("{name}: on_temp must be between 1000 and 25000");
+        }
+        Ok(())
+    }
+}
+
+fn default_on_temp() -> u32 {
+    4500
+}
+
+// What `redshift -p` reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedshiftStatus {
+    // e.g. "Daytime", "Night" or "Transition (67.35% day)"
+    pub period: String,
+    pub temperature: u32,
+}
+
+// NightLightItem shows a moon while on and a sun while off
+pub struct NightLightItem {
+    on_temp: u32,
+    // How often to re-query redshift
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets
+    widgets: RefCell<Option<(Image, Label)>>,
+    // Shared with the click handler and in-flight queries
+    state: Rc<NightLightState>,
+}
+
+struct NightLightState {
+    // Whether we set the night light; redshift can't be asked
+    on: Cell<bool>,
+    // A redshift command is still in flight
+    busy: Cell<bool>,
+}
+
+impl NightLightItem {
+    pub fn new(cfg: &NightLightConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("nightlight")?;
+        let refresh = item_refresh_interval(
+            "nightlight",
+            cfg.refresh_secs.unwrap_or(default_refresh_secs),
+        )?;
+
+        Ok(Self {
+            on_temp: cfg.on_temp,
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            state: Rc::new(NightLightState {
+                on: Cell::new(false),
+                busy: Cell::new(false),
+            }),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &NightLightConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Parse `redshift -p` output:
+//
+//   Using method `randr'.
+//   Period: Night
+//   Color temperature: 4500K
+//   Brightness: 1.00
+pub fn parse_redshift(output: &str) -> Result<RedshiftStatus> {
+    let field = |name: &str| {
+        output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(name))
+            .map(str::trim)
+            .with_context(|| format!("No {name:?} in redshift output"))
+    };
+    let period = field("Period:")?.to_string();
+    let temperature = field("Color temperature:")?;
+    let temperature = temperature
+        .trim_end_matches('K')
+        .parse()
+        .with_context(|| format!("Invalid colour temperature {temperature:?}"))?;
+    Ok(RedshiftStatus {
+        period,
+        temperature,
+    })
+}
+
+// Run redshift with `args` and return its stdout. Blocks.
+fn redshift(args: &[&str]) -> Result<String> {
+    let output = Command::new("redshift")
+        .args(args)
+        .output()
+        .context("Running redshift")?;
+    if !output.status.success() {
+        bail!(
+            "redshift exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// The label's text and visibility: the temperature while on, hidden
+// while off, and a short error while redshift is failing
+fn label_view(on: bool, on_temp: u32, failed: bool) -> (String, bool) {
+    if failed {
+        ("Redshift N/A".to_string(), true)
+    } else {
+        (format!("{on_temp}K"), on)
+    }
+}
+
+// Show the on/off state
+fn show_state(image: &Image, label: &Label, on: bool, on_temp: u32) {
+    layout::set_icon(image, if on { ICON_ON } else { ICON_OFF });
+    let (text, visible) = label_view(on, on_temp, false);
+    label.set_text(&text);
+    label.set_visible(visible);
+}
+
+// Show a failed redshift run on the label
+fn show_failure(label: &Label, on: bool, on_temp: u32, err: &anyhow::Error) {
+    let (text, visible) = label_view(on, on_temp, true);
+    label.set_visible(visible);
+    show_error(label, &text, err);
+}
+
+// Query redshift's schedule off the main thread for the tooltip
+fn refresh(image: &Image, label: &Label, state: &Rc<NightLightState>, on_temp: u32) {
+    if state.busy.replace(true) {
+        return;
+    }
+
+    let (image, label, state) = (image.clone(), label.clone(), state.clone());
+    glib::spawn_future_local(async move {
+        let result = gio::spawn_blocking(|| redshift(&["-p"]).and_then(|out| parse_redshift(&out)))
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("redshift thread panicked")));
+
+        match result {
+            Ok(status) => {
+                // Drop a previous failure's text from the label
+                clear_error(&label);
+                show_state(&image, &label, state.on.get(), on_temp);
+                let tooltip = format!("{}, scheduled {}K", status.period, status.temperature);
+                image.set_tooltip_text(Some(&tooltip));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to query redshift");
+                show_failure(&label, state.on.get(), on_temp, &e);
+            }
+        }
+        state.busy.set(false);
+    });
+}
+
+impl Item for NightLightItem {
+    fn name(&self) -> &str {
+        "nightlight"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("nightlight-label");
+        show_state(&image, &label, self.state.on.get(), self.on_temp);
+
+        // Left click flips the night light
+        let gesture = GestureClick::new();
+        let (clicked_image, clicked_label) = (image.clone(), label.clone());
+        let state = self.state.clone();
+        let on_temp = self.on_temp;
+        gesture.connect_released(move |_, _, _, _| {
+            let on = !state.on.get();
+            let (image, label, state) =
+                (clicked_image.clone(), clicked_label.clone(), state.clone());
+            glib::spawn_future_local(async move {
+                let result = gio::spawn_blocking(move || {
+                    if on {
+                        redshift(&["-P", "-O", &on_temp.to_string()])
+                    } else {
+                        redshift(&["-x"])
+                    }
+                })
+                .await
+                .unwrap_or_else(|_| Err(anyhow::anyhow!("redshift thread panicked")));
+
+                match result {
+                    Ok(_) => {
+                        clear_error(&label);
+                        state.on.set(on);
+                        show_state(&image, &label, on, on_temp);
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Failed to toggle night light");
+                        show_failure(&label, state.on.get(), on_temp, &e);
+                    }
+                }
+            });
+        });
+        container.add_controller(gesture);
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Query right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some((image, label)) = self.widgets.borrow().as_ref() {
+            refresh(image, label, &self.state, self.on_temp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RedshiftStatus, label_view, parse_redshift};
+
+    #[test]
+    fn parses_redshift_print_output() {
+        let night =
+            "Using method `randr'.\nPeriod: Night\nColor temperature: 4500K\nBrightness: 1.00\n";
+        assert_eq!(
+            parse_redshift(night).unwrap(),
+            RedshiftStatus {
+                period: "Night".into(),
+                temperature: 4500
+            }
+        );
+
+        let dusk = "Location: 45.07 N, 7.69 E\nPeriod: Transition (67.35% day)\nColor temperature: 5845K\n";
+        let status = parse_redshift(dusk).unwrap();
+        assert_eq!(status.period, "Transition (67.35% day)");
+        assert_eq!(status.temperature, 5845);
+
+        assert!(parse_redshift("Period: Night\n").is_err());
+        assert!(parse_redshift("Period: Night\nColor temperature: warm\n").is_err());
+    }
+
+    #[test]
+    fn label_recovers_after_an_error() {
+        // Off, then redshift fails: the error shows even though it's off
+        assert_eq!(label_view(false, 4500, true), ("Redshift N/A".into(), true));
+        // The next good query hides the label again
+        assert_eq!(label_view(false, 4500, false), ("4500K".into(), false));
+        assert_eq!(label_view(true, 4500, false), ("4500K".into(), true));
+    }
+}
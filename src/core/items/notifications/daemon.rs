@@ -0,0 +1,163 @@
+// src/core/items/notifications/daemon.rs
+//
+// Talks to the notification daemon through its control tool:
+// `dunstctl` for dunst, `makoctl` for mako. All calls block and run on
+// a worker thread.
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde_json::Value;
+use std::process::Command;
+
+// Mako has no pause flag; do-not-disturb is a mode by convention
+const MAKO_DND_MODE: &str = "do-not-disturb";
+
+// Which notification daemon to ask
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Daemon {
+    #[default]
+    Dunst,
+    Mako,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationStatus {
+    // Notifications waiting for the user: shown or held back by DND
+    pub count: usize,
+    pub dnd: bool,
+}
+
+impl NotificationStatus {
+    pub fn icon(&self) -> &'static [&'static str] {
+        if self.dnd {
+            &[
+                "notifications-disabled-symbolic",
+                "preferences-system-notifications-symbolic",
+            ]
+        } else {
+            &[
+                "notifications-symbolic",
+                "preferences-system-notifications-symbolic",
+            ]
+        }
+    }
+}
+
+impl Daemon {
+    pub fn status(self) -> Result<NotificationStatus> {
+        match self {
+            Daemon::Dunst => {
+                let count = parse_dunst_count(&run("dunstctl", &["count"])?)?;
+                let paused = run("dunstctl", &["is-paused"])?;
+                Ok(NotificationStatus {
+                    count,
+                    dnd: paused.trim() == "true",
+                })
+            }
+            Daemon::Mako => Ok(NotificationStatus {
+                count: parse_mako_list(&run("makoctl", &["list"])?)?,
+                dnd: parse_mako_modes(&run("makoctl", &["mode"])?),
+            }),
+        }
+    }
+
+    pub fn toggle_dnd(self) -> Result<()> {
+        match self {
+            Daemon::Dunst => run("dunstctl", &["set-paused", "toggle"])?,
+            Daemon::Mako => run("makoctl", &["mode", "-t", MAKO_DND_MODE])?,
+        };
+        Ok(())
+    }
+}
+
+// Run a control tool and return its stdout
+fn run(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .with_context(|| format!("Running {program}"))?;
+    if !output.status.success() {
+        bail!(
+            "{program} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// Waiting plus displayed notifications from `dunstctl count`:
+//
+//                Waiting: 2
+//    Currently displayed: 1
+//                History: 14
+pub fn parse_dunst_count(output: &str) -> Result<usize> {
+    let field = |name: &str| -> Result<usize> {
+        let value = output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(name))
+            .with_context(|| format!("No {name:?} in dunstctl output"))?;
+        value
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid count {value:?}"))
+    };
+    Ok(field("Waiting:")? + field("Currently displayed:")?)
+}
+
+// Number of notifications in `makoctl list`. Mako 1.9 prints text with
+// one "Notification <id>: ..." header each; older releases print JSON.
+pub fn parse_mako_list(output: &str) -> Result<usize> {
+    let output = output.trim();
+    if !output.starts_with('{') {
+        return Ok(output
+            .lines()
+            .filter(|line| line.starts_with("Notification "))
+            .count());
+    }
+
+    let json: Value = serde_json::from_str(output).context("Invalid makoctl JSON")?;
+    json.get("data")
+        .and_then(Value::as_array)
+        .and_then(|data| data.first())
+        .and_then(Value::as_array)
+        .map(Vec::len)
+        .context("No notification list in makoctl output")
+}
+
+// Whether the do-not-disturb mode is among the active `makoctl mode`s
+pub fn parse_mako_modes(output: &str) -> bool {
+    output.lines().any(|mode| mode.trim() == MAKO_DND_MODE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_dunst_count, parse_mako_list, parse_mako_modes};
+
+    #[test]
+    fn parses_dunst_count() {
+        let out = "              Waiting: 2\n  Currently displayed: 1\n              History: 14\n";
+        assert_eq!(parse_dunst_count(out).unwrap(), 3);
+        assert!(parse_dunst_count("History: 14\n").is_err());
+        assert!(parse_dunst_count("Waiting: x\nCurrently displayed: 0\n").is_err());
+    }
+
+    #[test]
+    fn parses_mako_list_text_and_json() {
+        let text = "Notification 12: Build done\n  App name: make\n  Urgency: normal\n\
+                    Notification 11: Hello\n  App name: notify-send\n";
+        assert_eq!(parse_mako_list(text).unwrap(), 2);
+        assert_eq!(parse_mako_list("").unwrap(), 0);
+
+        let json = r#"{"type": "aa{sv}", "data": [[{"id": {"type": "u", "data": 3}}]]}"#;
+        assert_eq!(parse_mako_list(json).unwrap(), 1);
+        assert!(parse_mako_list(r#"{"type": "aa{sv}"}"#).is_err());
+    }
+
+    #[test]
+    fn detects_mako_dnd_mode() {
+        assert!(parse_mako_modes("default\ndo-not-disturb\n"));
+        assert!(!parse_mako_modes("default\n"));
+    }
+}
@@ -0,0 +1,197 @@
+// src/core/items/notifications/item.rs
+//
+// A status-bar item showing how many notifications are waiting and
+// whether do-not-disturb is on, updating every `refresh_secs` seconds.
+// Left click toggles do-not-disturb.
+
+use super::daemon::{Daemon, NotificationStatus};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::reads::ReadGate;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Image, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for the `notifications` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NotificationConfig {
+    #[serde(default)]
+    pub daemon: Daemon,
+    // How often (in seconds) to re-query the daemon; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for NotificationConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// NotificationItem shows a bell and the waiting count
+pub struct NotificationItem {
+    daemon: Daemon,
+    // How often to re-query the daemon
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+    // One query in flight at a time; ticks skip, toggles queue
+    reads: Rc<ReadGate>,
+}
+
+impl NotificationItem {
+    pub fn new(cfg: &NotificationConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("notifications")?;
+        let refresh = item_refresh_interval(
+            "notifications",
+            cfg.refresh_secs.unwrap_or(default_refresh_secs),
+        )?;
+
+        Ok(Self {
+            daemon: cfg.daemon,
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            reads: Rc::new(ReadGate::default()),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &NotificationConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Query the daemon for a tick, unless a query is already in flight
+fn refresh(image: &Image, label: &Label, daemon: Daemon, reads: &Rc<ReadGate>) {
+    if reads.start() {
+        query(image, label, daemon, reads);
+    }
+}
+
+// Query the daemon after a toggle, queued behind any query in flight
+fn refresh_after_toggle(image: &Image, label: &Label, daemon: Daemon, reads: &Rc<ReadGate>) {
+    if reads.start_or_queue() {
+        query(image, label, daemon, reads);
+    }
+}
+
+// Query the daemon off the main thread and show the result
+fn query(image: &Image, label: &Label, daemon: Daemon, reads: &Rc<ReadGate>) {
+    let (image, label, reads) = (image.clone(), label.clone(), reads.clone());
+    glib::spawn_future_local(async move {
+        let result = gio::spawn_blocking(move || daemon.status())
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("query thread panicked")));
+
+        match result {
+            Ok(status) => {
+                clear_error(&label);
+                show_status(&image, &label, status);
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to query notification daemon");
+                label.set_visible(true);
+                show_error(&label, "Notif N/A", &e);
+            }
+        }
+        if reads.finish() {
+            query(&image, &label, daemon, &reads);
+        }
+    });
+}
+
+fn show_status(image: &Image, label: &Label, status: NotificationStatus) {
+    layout::set_icon(image, status.icon());
+    label.set_text(&status.count.to_string());
+    // Nothing waiting: the bell alone says enough
+    label.set_visible(status.count > 0);
+    if status.dnd {
+        label.add_css_class("dnd");
+    } else {
+        label.remove_css_class("dnd");
+    }
+}
+
+impl Item for NotificationItem {
+    fn name(&self) -> &str {
+        "notifications"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("notifications-label");
+        show_status(
+            &image,
+            &label,
+            NotificationStatus {
+                count: 0,
+                dnd: false,
+            },
+        );
+
+        // Left click toggles do-not-disturb, then re-queries so the icon
+        // follows
+        let gesture = GestureClick::new();
+        let (clicked_image, clicked_label) = (image.clone(), label.clone());
+        let (daemon, reads) = (self.daemon, self.reads.clone());
+        gesture.connect_released(move |_, _, _, _| {
+            let (image, label, reads) =
+                (clicked_image.clone(), clicked_label.clone(), reads.clone());
+            glib::spawn_future_local(async move {
+                let toggled = gio::spawn_blocking(move || daemon.toggle_dnd())
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("toggle thread panicked")));
+                if let Err(e) = toggled {
+                    warn!(error = %e, "Failed to toggle do-not-disturb");
+                }
+                refresh_after_toggle(&image, &label, daemon, &reads);
+            });
+        });
+        container.add_controller(gesture);
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Query right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some((image, label)) = self.widgets.borrow().as_ref() {
+            refresh(image, label, self.daemon, &self.reads);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// src/core/items/notifications/mod.rs
+//! Shows the dunst or mako notification count and do-not-disturb state.
+
+mod daemon;
+mod item;
+
+pub use daemon::{
+    Daemon, NotificationStatus, parse_dunst_count, parse_mako_list, parse_mako_modes,
+};
+pub use item::{NotificationConfig, NotificationItem};
@@ -0,0 +1,273 @@
+// src/core/items/power_menu.rs
+//
+// A status-bar button opening a menu of session and power actions:
+// lock, log out, suspend, reboot and shut down.
+
+use crate::core::actions::spawn_command;
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::popover::attach_popover;
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Button, Label, Orientation, Popover, Widget};
+use serde::Deserialize;
+
+const ICON: &[&str] = &["system-shutdown-symbolic", "system-log-out-symbolic"];
+
+// The entries of the menu, top to bottom
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PowerAction {
+    Lock,
+    Logout,
+    Suspend,
+    Reboot,
+    Shutdown,
+}
+
+impl PowerAction {
+    pub const ALL: [PowerAction; 5] = [
+        PowerAction::Lock,
+        PowerAction::Logout,
+        PowerAction::Suspend,
+        PowerAction::Reboot,
+        PowerAction::Shutdown,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerAction::Lock => "Lock",
+            PowerAction::Logout => "Log Out",
+            PowerAction::Suspend => "Suspend",
+            PowerAction::Reboot => "Reboot",
+            PowerAction::Shutdown => "Shut Down",
+        }
+    }
+
+    // Run through `sh -c`, so `$USER` expands
+    pub fn default_command(self) -> &'static str {
+        match self {
+            PowerAction::Lock => "loginctl lock-session",
+            PowerAction::Logout => "loginctl terminate-user $USER",
+            PowerAction::Suspend => "systemctl suspend",
+            PowerAction::Reboot => "systemctl reboot",
+            PowerAction::Shutdown => "systemctl poweroff",
+        }
+    }
+}
+
+// Which actions ask "are you sure?" first: `true` for reboot and shut
+// down, or a list of actions
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Confirm {
+    All(bool),
+    Only(Vec<PowerAction>),
+}
+
+impl Default for Confirm {
+    fn default() -> Self {
+        Confirm::All(false)
+    }
+}
+
+impl Confirm {
+    pub fn applies_to(&self, action: PowerAction) -> bool {
+        match self {
+            Confirm::All(on) => {
+                *on && matches!(action, PowerAction::Reboot | PowerAction::Shutdown)
+            }
+            Confirm::Only(actions) => actions.contains(&action),
+        }
+    }
+}
+
+// Settings for the `power_menu` item; unset commands use the defaults
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PowerMenuConfig {
+    pub lock: Option<String>,
+    pub logout: Option<String>,
+    pub suspend: Option<String>,
+    pub reboot: Option<String>,
+    pub shutdown: Option<String>,
+    #[serde(default)]
+    pub confirm: Confirm,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for PowerMenuConfig {}
+
+impl PowerMenuConfig {
+    // The command `action` runs
+    pub fn command(&self, action: PowerAction) -> String {
+        let configured = match action {
+            PowerAction::Lock => &self.lock,
+            PowerAction::Logout => &self.logout,
+            PowerAction::Suspend => &self.suspend,
+            PowerAction::Reboot => &self.reboot,
+            PowerAction::Shutdown => &self.shutdown,
+        };
+        configured
+            .clone()
+            .unwrap_or_else(|| action.default_command().to_string())
+    }
+}
+
+// One menu entry
+#[derive(Debug, Clone)]
+struct Entry {
+    action: PowerAction,
+    command: String,
+    // Ask before running `command`
+    confirm: bool,
+}
+
+// PowerMenuItem shows a power icon that opens the menu on click
+pub struct PowerMenuItem {
+    entries: Vec<Entry>,
+}
+
+impl PowerMenuItem {
+    pub fn new(cfg: &PowerMenuConfig) -> Self {
+        Self {
+            entries: PowerAction::ALL
+                .into_iter()
+                .map(|action| Entry {
+                    action,
+                    command: cfg.command(action),
+                    confirm: cfg.confirm.applies_to(action),
+                })
+                .collect(),
+        }
+    }
+}
+
+// A button that closes the popover and runs `command`
+fn run_button(label: &str, command: &str, popover: &Popover) -> Button {
+    let button = Button::with_label(label);
+    // Weak: the popover owns this button
+    let popover = popover.downgrade();
+    let command = command.to_string();
+    button.connect_clicked(move |_| {
+        if let Some(popover) = popover.upgrade() {
+            popover.popdown();
+        }
+        spawn_command(&command);
+    });
+    button
+}
+
+// The list of actions
+fn menu(entries: &[Entry], popover: &Popover) -> GtkBox {
+    let list = GtkBox::new(Orientation::Vertical, 2);
+    for entry in entries {
+        let button = if entry.confirm {
+            // Swap the menu for a prompt instead of running anything
+            let button = Button::with_label(entry.action.label());
+            let weak = popover.downgrade();
+            let entry = entry.clone();
+            button.connect_clicked(move |_| {
+                if let Some(popover) = weak.upgrade() {
+                    popover.set_child(Some(&confirmation(&entry, &popover)));
+                }
+            });
+            button
+        } else {
+            run_button(entry.action.label(), &entry.command, popover)
+        };
+        button.add_css_class("flat");
+        list.append(&button);
+    }
+    list
+}
+
+// "Reboot now?" with Cancel and the action itself. Only the action
+// button runs the command; cancelling or closing the popover doesn't,
+// and the next click on the item shows the menu again.
+fn confirmation(entry: &Entry, popover: &Popover) -> GtkBox {
+    let prompt = GtkBox::new(Orientation::Vertical, 6);
+    prompt.add_css_class("confirm");
+    prompt.append(&Label::new(Some(&format!("{} now?", entry.action.label()))));
+
+    let buttons = GtkBox::new(Orientation::Horizontal, 6);
+    let cancel = Button::with_label("Cancel");
+    let weak = popover.downgrade();
+    cancel.connect_clicked(move |_| {
+        if let Some(popover) = weak.upgrade() {
+            popover.popdown();
+        }
+    });
+    buttons.append(&cancel);
+
+    let confirm = run_button(entry.action.label(), &entry.command, popover);
+    confirm.add_css_class("destructive-action");
+    buttons.append(&confirm);
+
+    prompt.append(&buttons);
+    prompt
+}
+
+impl Item for PowerMenuItem {
+    fn name(&self) -> &str {
+        "power_menu"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+        container.add_css_class("power-menu");
+
+        let image = layout::icon();
+        layout::set_icon(&image, ICON);
+        container.append(&image);
+
+        let entries = self.entries.clone();
+        attach_popover(&container, "power-menu", move |popover| {
+            menu(&entries, popover).upcast()
+        });
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Nothing to poll
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Confirm, PowerAction, PowerMenuConfig};
+
+    #[test]
+    fn commands_default_unless_configured() {
+        let cfg: PowerMenuConfig = toml::from_str(
+            r#"
+            lock = "swaylock -f"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.command(PowerAction::Lock), "swaylock -f");
+        assert_eq!(cfg.command(PowerAction::Shutdown), "systemctl poweroff");
+        assert_eq!(
+            cfg.command(PowerAction::Logout),
+            "loginctl terminate-user $USER"
+        );
+        assert_eq!(cfg.confirm, Confirm::All(false));
+    }
+
+    #[test]
+    fn confirms_chosen_actions() {
+        let cfg: PowerMenuConfig = toml::from_str("confirm = true").unwrap();
+        assert!(cfg.confirm.applies_to(PowerAction::Reboot));
+        assert!(cfg.confirm.applies_to(PowerAction::Shutdown));
+        assert!(!cfg.confirm.applies_to(PowerAction::Lock));
+
+        let cfg: PowerMenuConfig = toml::from_str(r#"confirm = ["logout"]"#).unwrap();
+        assert!(cfg.confirm.applies_to(PowerAction::Logout));
+        assert!(!cfg.confirm.applies_to(PowerAction::Shutdown));
+
+        assert!(toml::from_str::<PowerMenuConfig>(r#"confirm = ["hibernate"]"#).is_err());
+    }
+}
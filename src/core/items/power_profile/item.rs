@@ -0,0 +1,192 @@
+// src/core/items/power_profile/item.rs
+//
+// A status-bar item showing the active power-profiles-daemon profile.
+// Clicking it switches to the next profile.
+
+use super::profiles::{icon_for, next_profile};
+use crate::core::config::{IconPosition, ItemCommon, ItemDisplay, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Context, Result, bail};
+use gtk4::gio::{self, BusType, DBusCallFlags, DBusProxy, DBusProxyFlags};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Image, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use tracing::warn;
+
+const BUS_NAME: &str = "net.hadess.PowerProfiles";
+const OBJECT_PATH: &str = "/net/hadess/PowerProfiles";
+const INTERFACE: &str = "net.hadess.PowerProfiles";
+// For themes predating the power-profile-* icons
+const FALLBACK_ICON: &str = "preferences-system-power-symbolic";
+
+// Settings for the `power_profile` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PowerProfileConfig {
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for PowerProfileConfig {}
+
+// PowerProfileItem shows the active profile with a matching icon
+pub struct PowerProfileItem {
+    // Caches the daemon's properties and follows their changes
+    proxy: DBusProxy,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated when the profile changes
+    widgets: RefCell<Option<(Image, Label)>>,
+    // The property-change handler, disconnected again by stop()
+    handler_id: RefCell<Option<glib::SignalHandlerId>>,
+}
+
+impl PowerProfileItem {
+    // Connect to power-profiles-daemon on the system bus.
+    // Fails when the daemon isn't running, so the item is skipped.
+    pub fn new(cfg: &PowerProfileConfig) -> Result<Self> {
+        let proxy = DBusProxy::for_bus_sync(
+            BusType::System,
+            DBusProxyFlags::NONE,
+            None,
+            BUS_NAME,
+            OBJECT_PATH,
+            INTERFACE,
+            gio::Cancellable::NONE,
+        )
+        .context("Connecting to power-profiles-daemon")?;
+
+        if proxy.name_owner().is_none() {
+            bail!("power-profiles-daemon is not running");
+        }
+
+        Ok(Self {
+            proxy,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            handler_id: RefCell::new(None),
+        })
+    }
+}
+
+// The active profile, as cached by the proxy
+fn active_profile(proxy: &DBusProxy) -> Option<String> {
+    proxy
+        .cached_property("ActiveProfile")
+        .and_then(|v| v.get::<String>())
+}
+
+// Names of the profiles the daemon offers (`Profiles` is `aa{sv}`)
+fn available_profiles(proxy: &DBusProxy) -> Vec<String> {
+    let Some(profiles) = proxy.cached_property("Profiles") else {
+        return Vec::new();
+    };
+    profiles
+        .iter()
+        .filter_map(|entry| {
+            glib::VariantDict::new(Some(&entry))
+                .lookup::<String>("Profile")
+                .ok()
+                .flatten()
+        })
+        .collect()
+}
+
+// Show the active profile on the icon and label
+fn update_widgets(proxy: &DBusProxy, image: &Image, label: &Label) {
+    match active_profile(proxy) {
+        Some(profile) => {
+            layout::set_icon(image, &[icon_for(&profile), FALLBACK_ICON]);
+            label.set_text(&profile);
+        }
+        None => label.set_text("Profile N/A"),
+    }
+}
+
+// Ask the daemon to switch to the profile after the active one
+fn switch_to_next(proxy: &DBusProxy) {
+    let current = active_profile(proxy).unwrap_or_default();
+    let available = available_profiles(proxy);
+    let available: Vec<&str> = available.iter().map(String::as_str).collect();
+    let Some(next) = next_profile(&current, &available) else {
+        return;
+    };
+
+    // Write the property through org.freedesktop.DBus.Properties.Set
+    let args = (INTERFACE, "ActiveProfile", next.to_variant()).to_variant();
+    proxy.call(
+        "org.freedesktop.DBus.Properties.Set",
+        Some(&args),
+        DBusCallFlags::NONE,
+        -1,
+        gio::Cancellable::NONE,
+        |result| {
+            if let Err(e) = result {
+                warn!(error = %e, "Failed to switch power profile");
+            }
+        },
+    );
+}
+
+impl Item for PowerProfileItem {
+    fn name(&self) -> &str {
+        "power_profile"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("power-profile-label");
+
+        // Set initial state
+        update_widgets(&self.proxy, &image, &label);
+
+        // Left click cycles to the next profile
+        let gesture = GestureClick::new();
+        let proxy = self.proxy.clone();
+        gesture.connect_released(move |_, _, _, _| switch_to_next(&proxy));
+        container.add_controller(gesture);
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Grab the initialized widgets - panic if widget() wasn't called
+        let (image, label) = self
+            .widgets
+            .borrow()
+            .as_ref()
+            .expect("widget() must be called before start()")
+            .clone();
+
+        // The proxy tracks PropertiesChanged for us, so just redraw when
+        // its cache changes instead of polling
+        let proxy = self.proxy.clone();
+        let id = self
+            .proxy
+            .connect_local("g-properties-changed", false, move |_| {
+                update_widgets(&proxy, &image, &label);
+                None
+            });
+        *self.handler_id.borrow_mut() = Some(id);
+        Ok(())
+    }
+
+    fn stop(&self) {
+        if let Some(id) = self.handler_id.borrow_mut().take() {
+            self.proxy.disconnect(id);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+// src/core/items/power_profile/mod.rs
+//! Shows and switches the active power-profiles-daemon profile.
+
+mod item;
+mod profiles;
+
+pub use item::{PowerProfileConfig, PowerProfileItem};
+pub use profiles::{icon_for, next_profile};
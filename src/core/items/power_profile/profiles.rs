@@ -0,0 +1,52 @@
+// src/core/items/power_profile/profiles.rs
+//
+// The profiles power-profiles-daemon knows about, in cycling order.
+
+// From most frugal to fastest
+const PROFILES: [&str; 3] = ["power-saver", "balanced", "performance"];
+
+// The profile a click switches to: the next one in `PROFILES` that the
+// daemon offers, wrapping around. Unknown profiles restart the cycle.
+pub fn next_profile<'a>(current: &str, available: &[&'a str]) -> Option<&'a str> {
+    let offered: Vec<&'a str> = PROFILES
+        .iter()
+        .filter_map(|p| available.iter().find(|a| *a == p).copied())
+        .collect();
+
+    let next = match offered.iter().position(|p| *p == current) {
+        Some(i) => (i + 1) % offered.len(),
+        None => 0,
+    };
+    offered.get(next).copied()
+}
+
+// Symbolic icon for a profile
+pub fn icon_for(profile: &str) -> &'static str {
+    match profile {
+        "power-saver" => "power-profile-power-saver-symbolic",
+        "performance" => "power-profile-performance-symbolic",
+        _ => "power-profile-balanced-symbolic",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_profile;
+
+    #[test]
+    fn cycles_through_offered_profiles() {
+        let all = ["performance", "balanced", "power-saver"];
+        assert_eq!(next_profile("power-saver", &all), Some("balanced"));
+        assert_eq!(next_profile("balanced", &all), Some("performance"));
+        assert_eq!(next_profile("performance", &all), Some("power-saver"));
+    }
+
+    #[test]
+    fn skips_profiles_the_daemon_lacks() {
+        // Machines without platform support have no "performance"
+        let two = ["power-saver", "balanced"];
+        assert_eq!(next_profile("balanced", &two), Some("power-saver"));
+        assert_eq!(next_profile("unknown", &two), Some("power-saver"));
+        assert_eq!(next_profile("balanced", &[]), None);
+    }
+}
@@ -0,0 +1,183 @@
+// src/core/items/procs.rs
+//
+// A status-bar item showing how many processes are running,
+// updating every `refresh_secs` seconds.
+//
+// Every process has a numeric directory under `/proc`; counting them is
+// far cheaper than reading each one's status.
+
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+// Overrides the proc root, mainly for tests
+pub const PROC_BASE_ENV: &str = "PROC_BASE";
+const PROC_BASE: &str = "/proc";
+
+// Settings for the `procs` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ProcsConfig {
+    // How often (in seconds) to recount; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for ProcsConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// ProcsItem shows the number of running processes
+pub struct ProcsItem {
+    base: PathBuf,
+    // How often to recount
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+}
+
+impl ProcsItem {
+    pub fn new(cfg: &ProcsConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("procs")?;
+        let refresh =
+            item_refresh_interval("procs", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        Ok(Self {
+            base: proc_base(),
+            refresh,
+            label: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &ProcsConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// The proc root: `$PROC_BASE` if set, `/proc` otherwise
+pub fn proc_base() -> PathBuf {
+    std::env::var_os(PROC_BASE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(PROC_BASE))
+}
+
+// Number of process directories (all-digit names) under `base`
+pub fn count_procs(base: &Path) -> Result<usize> {
+    let entries = fs::read_dir(base).with_context(|| format!("Listing {base:?}"))?;
+    Ok(entries
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            !name.is_empty() && name.bytes().all(|b| b.is_ascii_digit())
+        })
+        .count())
+}
+
+// Refresh the label with the current count
+fn update_label(label: &Label, base: &Path) {
+    match count_procs(base) {
+        Ok(count) => {
+            clear_error(label);
+            label.set_text(&count.to_string());
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to count processes");
+            show_error(label, "Procs N/A", &e);
+        }
+    }
+}
+
+impl Item for ProcsItem {
+    fn name(&self) -> &str {
+        "procs"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("procs-label");
+
+        // Set initial text
+        update_label(&label, &self.base);
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, &self.base);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProcsConfig, ProcsItem, count_procs};
+    use crate::core::item::Item;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn counts_only_numeric_entries() {
+        let base = std::env::temp_dir().join(format!("panel-rs-procs-{}", std::process::id()));
+        for dir in ["1", "42", "31337", "self", "sys", "1a", "acpi"] {
+            fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        fs::write(base.join("uptime"), "1.0 2.0\n").unwrap();
+
+        assert_eq!(count_procs(&base).unwrap(), 3);
+        assert!(count_procs(&base.join("missing")).is_err());
+
+        fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn with_defaults_polls_every_second() {
+        let item = ProcsItem::with_defaults(&ProcsConfig::default()).unwrap();
+        assert_eq!(item.refresh_interval(), Some(Duration::from_secs(1)));
+
+        let cfg = ProcsConfig {
+            refresh_secs: Some(0.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            ProcsItem::with_defaults(&cfg).unwrap().refresh_interval(),
+            Some(Duration::from_millis(500))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+// src/core/items/psi.rs
+//
+// A status-bar item showing pressure stall information: the share of
+// the last 10 seconds in which tasks waited on CPU, memory or I/O.
+// Updates every `refresh_secs` seconds.
+//
+// Reads `/proc/pressure/<resource>` (Linux 4.20+), which looks like:
+//
+//   some avg10=1.53 avg60=0.87 avg300=0.34 total=2304561
+//   full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+
+use super::procs::proc_base;
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::level::level_class;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+// Which resource's pressure to show
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Resource {
+    Cpu,
+    #[default]
+    Memory,
+    Io,
+}
+
+impl Resource {
+    fn file_name(self) -> &'static str {
+        match self {
+            Resource::Cpu => "cpu",
+            Resource::Memory => "memory",
+            Resource::Io => "io",
+        }
+    }
+}
+
+// `some`: at least one task stalled; `full`: all non-idle tasks stalled
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Field {
+    #[default]
+    Some,
+    Full,
+}
+
+impl Field {
+    fn prefix(self) -> &'static str {
+        match self {
+            Field::Some => "some",
+            Field::Full => "full",
+        }
+    }
+}
+
+// Settings for the `psi` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct PsiConfig {
+    #[serde(default)]
+    pub resource: Resource,
+    #[serde(default)]
+    pub field: Field,
+    // Pressure (%) above which the label gets the `warn` class
+    #[serde(default = "default_warn")]
+    pub warn: f64,
+    // Pressure (%) above which the label gets the `critical` class
+    #[serde(default = "default_critical")]
+    pub critical: f64,
+    // How often (in seconds) to re-read; defaults to the global
+    // `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for PsiConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+fn default_warn() -> f64 {
+    10.0
+}
+
+fn default_critical() -> f64 {
+    25.0
+}
+
+// PsiItem shows e.g. `mem 1.5%`, coloured by threshold
+pub struct PsiItem {
+    path: PathBuf,
+    resource: Resource,
+    field: Field,
+    warn: f64,
+    critical: f64,
+    // How often to re-read
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+}
+
+impl PsiItem {
+    // Fails on kernels without PSI, so the item is skipped
+    pub fn new(cfg: &PsiConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("psi")?;
+        let refresh =
+            item_refresh_interval("psi", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        let path = proc_base().join("pressure").join(cfg.resource.file_name());
+        if !path.exists() {
+            bail!("No pressure information at {path:?}");
+        }
+
+        Ok(Self {
+            path,
+            resource: cfg.resource,
+            field: cfg.field,
+            warn: cfg.warn,
+            critical: cfg.critical,
+            refresh,
+            label: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &PsiConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// The `avg10` percentage of `field` in a pressure file
+pub fn parse_psi(raw: &str, field: Field) -> Result<f64> {
+    let line = raw
+        .lines()
+        .find(|line| line.split_whitespace().next() == Some(field.prefix()))
+        .with_context(|| format!("No {:?} line in pressure file", field.prefix()))?;
+    let avg10 = line
+        .split_whitespace()
+        .find_map(|pair| pair.strip_prefix("avg10="))
+        .with_context(|| format!("No avg10 in {line:?}"))?;
+    avg10
+        .parse()
+        .with_context(|| format!("Invalid avg10 {avg10:?}"))
+}
+
+fn read_psi(path: &Path, field: Field) -> Result<f64> {
+    let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+    parse_psi(&raw, field)
+}
+
+// Refresh the label and its threshold class
+fn update_label(label: &Label, item: &PsiItem) {
+    match read_psi(&item.path, item.field) {
+        Ok(pressure) => {
+            clear_error(label);
+            label.set_text(&format!("{} {pressure:.1}%", item.resource.file_name()));
+            for class in ["normal", "warn", "critical"] {
+                label.remove_css_class(class);
+            }
+            label.add_css_class(level_class(pressure, item.warn, item.critical));
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to read pressure");
+            show_error(label, "PSI N/A", &e);
+        }
+    }
+}
+
+impl Item for PsiItem {
+    fn name(&self) -> &str {
+        "psi"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("psi-label");
+
+        // Set initial text
+        update_label(&label, self);
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Field, parse_psi};
+
+    const MEMORY: &str = "\
+some avg10=1.53 avg60=0.87 avg300=0.34 total=2304561
+full avg10=0.42 avg60=0.20 avg300=0.05 total=801234
+";
+
+    #[test]
+    fn parses_avg10_of_each_field() {
+        assert_eq!(parse_psi(MEMORY, Field::Some).unwrap(), 1.53);
+        assert_eq!(parse_psi(MEMORY, Field::Full).unwrap(), 0.42);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        // Older kernels have no `full` line for cpu
+        assert!(
+            parse_psi(
+                "some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n",
+                Field::Full
+            )
+            .is_err()
+        );
+        assert!(parse_psi("some avg60=0.00\n", Field::Some).is_err());
+        assert!(parse_psi("some avg10=high\n", Field::Some).is_err());
+    }
+}
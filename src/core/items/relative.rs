@@ -0,0 +1,231 @@
+// src/core/items/relative.rs
+//
+// A status-bar item showing how long ago something happened, e.g.
+// "backup 3h ago", from a fixed timestamp or a file's modification time.
+// Updates every `refresh_secs` seconds (a minute by default).
+
+use crate::core::config::{ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for a `relative` or `relative/<id>` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct RelativeConfig {
+    // An RFC 3339 timestamp, or a file path when `from_file` is set
+    pub since: String,
+    // Use the modification time of the file at `since`
+    #[serde(default)]
+    pub from_file: bool,
+    // Label text; `{}` is replaced by e.g. "3h ago"
+    #[serde(default = "default_format")]
+    pub format: String,
+    // How often (in seconds) to redraw; defaults to 60
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for RelativeConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)?;
+        // A file's time can only be checked once the item reads it
+        if !self.from_file {
+            parse_since(name, &self.since)?;
+        }
+        Ok(())
+    }
+}
+
+// A fixed `since` timestamp
+fn parse_since(name: &str, since: &str) -> Result<DateTime<Utc>> {
+    let time = DateTime::parse_from_rfc3339(since.trim())
+        .with_context(|| format!("{name}: invalid RFC 3339 timestamp {since:?}"))?;
+    Ok(time.to_utc())
+}
+
+fn default_format() -> String {
+    "{}".to_string()
+}
+
+const DEFAULT_REFRESH_SECS: f64 = 60.0;
+
+// Where the reference time comes from
+#[derive(Debug, Clone)]
+enum Since {
+    Fixed(DateTime<Utc>),
+    // Re-read on every tick, so a refreshed file resets the count
+    File(PathBuf),
+}
+
+impl Since {
+    fn time(&self) -> Result<DateTime<Utc>> {
+        match self {
+            Since::Fixed(time) => Ok(*time),
+            Since::File(path) => {
+                let modified = fs::metadata(path)
+                    .and_then(|meta| meta.modified())
+                    .with_context(|| format!("Reading modification time of {path:?}"))?;
+                Ok(modified.into())
+            }
+        }
+    }
+}
+
+// RelativeItem shows the time elapsed since `since`
+pub struct RelativeItem {
+    // Config entry this item was built from, e.g. "relative/backup"
+    name: String,
+    since: Since,
+    format: String,
+    // How often to redraw
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+}
+
+impl RelativeItem {
+    pub fn new(name: &str, cfg: &RelativeConfig) -> Result<Self> {
+        cfg.validate(name)?;
+        let refresh =
+            item_refresh_interval(name, cfg.refresh_secs.unwrap_or(DEFAULT_REFRESH_SECS))?;
+
+        let since = if cfg.from_file {
+            Since::File(PathBuf::from(&cfg.since))
+        } else {
+            Since::Fixed(parse_since(name, &cfg.since)?)
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            since,
+            format: cfg.format.clone(),
+            refresh,
+            label: RefCell::new(None),
+        })
+    }
+}
+
+// A short human duration between two times: "42s ago", "5m ago",
+// "3h ago", "2d ago", or "in 5m" when `then` is in the future
+pub fn format_ago(now: DateTime<Utc>, then: DateTime<Utc>) -> String {
+    let secs = (now - then).num_seconds();
+    let amount = match secs.unsigned_abs() {
+        s @ 0..60 => format!("{s}s"),
+        s @ 60..3600 => format!("{}m", s / 60),
+        s @ 3600..86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
+    };
+    if secs < 0 {
+        format!("in {amount}")
+    } else {
+        format!("{amount} ago")
+    }
+}
+
+// Refresh the label from the reference time
+fn update_label(label: &Label, item: &RelativeItem) {
+    match item.since.time() {
+        Ok(then) => {
+            clear_error(label);
+            let ago = format_ago(Utc::now(), then);
+            label.set_text(&item.format.replace("{}", &ago));
+            label.set_tooltip_text(Some(&then.with_timezone(&chrono::Local).to_rfc2822()));
+        }
+        Err(e) => {
+            warn!(item = %item.name, error = %e, "Failed to read reference time");
+            show_error(label, "N/A", &e);
+        }
+    }
+}
+
+impl Item for RelativeItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("relative-label");
+
+        // Set initial text
+        update_label(&label, self);
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelativeConfig, RelativeItem, format_ago};
+    use chrono::{DateTime, Duration, Utc};
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2025-06-01T12:00:00Z")
+            .unwrap()
+            .to_utc()
+    }
+
+    #[test]
+    fn formats_each_unit_at_its_boundaries() {
+        let ago = |secs: i64| format_ago(now(), now() - Duration::seconds(secs));
+        assert_eq!(ago(0), "0s ago");
+        assert_eq!(ago(59), "59s ago");
+        assert_eq!(ago(60), "1m ago");
+        assert_eq!(ago(3599), "59m ago");
+        assert_eq!(ago(3600), "1h ago");
+        assert_eq!(ago(86399), "23h ago");
+        assert_eq!(ago(86400), "1d ago");
+        assert_eq!(ago(10 * 86400 + 5), "10d ago");
+        assert_eq!(ago(-300), "in 5m");
+    }
+
+    #[test]
+    fn parses_since() {
+        let cfg: RelativeConfig = toml::from_str(r#"since = "2025-05-31T12:00:00+02:00""#).unwrap();
+        assert_eq!(cfg.format, "{}");
+        assert!(RelativeItem::new("relative", &cfg).is_ok());
+
+        let cfg: RelativeConfig = toml::from_str(r#"since = "yesterday""#).unwrap();
+        assert!(RelativeItem::new("relative", &cfg).is_err());
+
+        // Files are only read when the label updates
+        let cfg: RelativeConfig =
+            toml::from_str("since = \"/var/backup/last\"\nfrom_file = true").unwrap();
+        assert!(RelativeItem::new("relative/backup", &cfg).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+// src/core/items/spacer.rs
+//
+// An empty status-bar item used for layout: by default it expands to
+// push its neighbours apart, or with `width` it becomes a fixed gap.
+
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Widget};
+use serde::Deserialize;
+
+// Settings for a `spacer` or `spacer/<id>` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SpacerConfig {
+    // Fixed size along the bar in pixels (a height in a vertical bar);
+    // unset means "take all the free space"
+    pub width: Option<u32>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for SpacerConfig {}
+
+pub struct SpacerItem {
+    // Config entry this item was built from, e.g. "spacer/gap"
+    name: String,
+    width: Option<u32>,
+}
+
+impl SpacerItem {
+    pub fn new(name: &str, cfg: &SpacerConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            width: cfg.width,
+        }
+    }
+}
+
+impl Item for SpacerItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let spacer = GtkBox::new(layout::orientation(), 0);
+        spacer.add_css_class("spacer");
+
+        let vertical = layout::is_vertical();
+        match self.width {
+            Some(width) if vertical => spacer.set_size_request(-1, width as i32),
+            Some(width) => spacer.set_size_request(width as i32, -1),
+            None if vertical => spacer.set_vexpand(true),
+            None => spacer.set_hexpand(true),
+        }
+        spacer.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Pure layout: nothing to start
+        Ok(())
+    }
+}
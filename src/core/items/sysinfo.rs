@@ -0,0 +1,171 @@
+// src/core/items/sysinfo.rs
+//
+// Status-bar items showing the hostname, kernel release or user name.
+// None of these change while the panel runs, so there is no timer:
+// the value is read once when the item is built.
+
+use super::procs::proc_base;
+use crate::core::config::{IconPosition, ItemCommon, ItemDisplay, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+// Settings for the `hostname`, `kernel` and `user` items
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SysInfoConfig {
+    // Icon name shown next to the text, e.g. "computer-symbolic"
+    pub icon: Option<String>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for SysInfoConfig {}
+
+// SysInfoItem shows one fixed fact about the system
+pub struct SysInfoItem {
+    // "hostname", "kernel" or "user"
+    name: &'static str,
+    text: String,
+    icon: Option<String>,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+}
+
+impl SysInfoItem {
+    // The `hostname` item, from `/proc/sys/kernel/hostname`
+    pub fn hostname(cfg: &SysInfoConfig) -> Result<Self> {
+        Ok(Self::new("hostname", hostname(&proc_base())?, cfg))
+    }
+
+    // The `kernel` item, from `/proc/sys/kernel/osrelease`
+    pub fn kernel(cfg: &SysInfoConfig) -> Result<Self> {
+        Ok(Self::new("kernel", kernel_release(&proc_base())?, cfg))
+    }
+
+    // The `user` item, from `$USER` (or `$LOGNAME`)
+    pub fn user(cfg: &SysInfoConfig) -> Result<Self> {
+        Ok(Self::new(
+            "user",
+            user_name(|var| std::env::var(var).ok())?,
+            cfg,
+        ))
+    }
+
+    fn new(name: &'static str, text: String, cfg: &SysInfoConfig) -> Self {
+        Self {
+            name,
+            text,
+            icon: cfg.icon.clone(),
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+        }
+    }
+}
+
+// Read a one-line proc file, failing when it is empty
+fn read_proc_value(path: &Path) -> Result<String> {
+    let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+    let value = raw.trim();
+    if value.is_empty() {
+        bail!("{path:?} is empty");
+    }
+    Ok(value.to_string())
+}
+
+// The hostname, as set in the kernel under the proc root `base`
+pub fn hostname(base: &Path) -> Result<String> {
+    read_proc_value(&base.join("sys/kernel/hostname"))
+}
+
+// The running kernel's release, e.g. "6.9.3-arch1-1"
+pub fn kernel_release(base: &Path) -> Result<String> {
+    read_proc_value(&base.join("sys/kernel/osrelease"))
+}
+
+// The login name from the environment; `var` looks up a variable
+pub fn user_name(var: impl Fn(&str) -> Option<String>) -> Result<String> {
+    ["USER", "LOGNAME"]
+        .into_iter()
+        .filter_map(var)
+        .find(|name| !name.is_empty())
+        .context("Neither $USER nor $LOGNAME is set")
+}
+
+impl Item for SysInfoItem {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        let label = Label::new(Some(&self.text));
+        label.add_css_class("sysinfo-label");
+        label.add_css_class(self.name);
+
+        match &self.icon {
+            Some(icon) => {
+                let image = layout::icon();
+                layout::set_icon(&image, &[icon]);
+                layout::append_icon_label(
+                    &container,
+                    &image,
+                    &label,
+                    self.icon_position,
+                    self.display,
+                );
+            }
+            None => container.append(&label),
+        }
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Fixed value: nothing to start
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hostname, kernel_release, user_name};
+    use std::fs;
+
+    #[test]
+    fn reads_kernel_values_from_proc() {
+        let base = std::env::temp_dir().join(format!("panel-rs-sysinfo-{}", std::process::id()));
+        fs::create_dir_all(base.join("sys/kernel")).unwrap();
+        fs::write(base.join("sys/kernel/hostname"), "thinkpad\n").unwrap();
+        fs::write(base.join("sys/kernel/osrelease"), "6.9.3-arch1-1\n").unwrap();
+
+        assert_eq!(hostname(&base).unwrap(), "thinkpad");
+        assert_eq!(kernel_release(&base).unwrap(), "6.9.3-arch1-1");
+
+        fs::write(base.join("sys/kernel/hostname"), "\n").unwrap();
+        assert!(hostname(&base).is_err());
+        assert!(kernel_release(&base.join("missing")).is_err());
+
+        fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn reads_user_from_environment() {
+        let env = |user: Option<&str>, logname: Option<&str>| {
+            let (user, logname) = (user.map(String::from), logname.map(String::from));
+            move |var: &str| match var {
+                "USER" => user.clone(),
+                "LOGNAME" => logname.clone(),
+                _ => None,
+            }
+        };
+        assert_eq!(user_name(env(Some("ada"), Some("root"))).unwrap(), "ada");
+        assert_eq!(user_name(env(Some(""), Some("ada"))).unwrap(), "ada");
+        assert!(user_name(env(None, None)).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+// src/core/items/text/item.rs
+//
+// A status-bar item showing fixed text and an optional icon.
+// It never changes, so there is no timer.
+
+use crate::core::config::{IconPosition, ItemCommon, ItemDisplay, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::markup::set_label_text;
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget};
+use serde::Deserialize;
+
+// Settings for a `text` or `text/<id>` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct TextConfig {
+    // Text to show
+    #[serde(default)]
+    pub text: String,
+    // Icon name shown next to the text, e.g. "starred-symbolic"
+    pub icon: Option<String>,
+    // Treat `text` as Pango markup
+    #[serde(default)]
+    pub markup: bool,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+// Deserializing checks everything
+impl ValidateConfig for TextConfig {}
+
+// TextItem shows its configured text and never updates
+pub struct TextItem {
+    // Config entry this item was built from, e.g. "text/sep"
+    name: String,
+    text: String,
+    icon: Option<String>,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    markup: bool,
+}
+
+impl TextItem {
+    pub fn new(name: &str, cfg: &TextConfig) -> Self {
+        Self {
+            name: name.to_string(),
+            text: cfg.text.clone(),
+            icon: cfg.icon.clone(),
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            markup: cfg.markup,
+        }
+    }
+}
+
+impl Item for TextItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        let label = Label::new(None);
+        set_label_text(&label, &self.text, self.markup);
+        label.add_css_class("text-label");
+
+        match &self.icon {
+            Some(icon) => {
+                let image = layout::icon();
+                layout::set_icon(&image, &[icon]);
+                layout::append_icon_label(
+                    &container,
+                    &image,
+                    &label,
+                    self.icon_position,
+                    self.display,
+                );
+            }
+            // Without an icon the text is all there is to show
+            None => container.append(&label),
+        }
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Static text: nothing to start
+        Ok(())
+    }
+}
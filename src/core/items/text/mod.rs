@@ -0,0 +1,15 @@
+// src/core/items/text/mod.rs
+//! A static label, e.g. a separator or a title between groups of items.
+//!
+//! Several can be configured with `text/<id>` entries:
+//!
+//! ```toml
+//! items = ["text/sep", "clock"]
+//!
+//! ["text/sep"]
+//! text = "|"
+//! ```
+
+mod item;
+
+pub use item::{TextConfig, TextItem};
@@ -0,0 +1,241 @@
+// src/core/items/timer.rs
+//
+// A status-bar countdown timer, or a stopwatch when no duration is set.
+// Left click starts and pauses it, right click resets it. A finished
+// countdown runs the configured `on_expire` command once.
+
+use crate::core::actions::spawn_command;
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Label, Widget};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+// GDK button numbers
+const BUTTON_LEFT: u32 = 1;
+const BUTTON_RIGHT: u32 = 3;
+
+// Settings for a `timer` or `timer/<id>` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct TimerConfig {
+    // Countdown length; unset makes a stopwatch
+    pub duration_secs: Option<u64>,
+    // Command run through `sh -c` when the countdown reaches zero
+    pub on_expire: Option<String>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for TimerConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        if self.duration_secs == Some(0) {
+            bail!("{name}: duration_secs must be at least 1");
+        }
+        Ok(())
+    }
+}
+
+// TimerItem shows `MM:SS` left (or elapsed) and counts while running
+pub struct TimerItem {
+    // Config entry this item was built from, e.g. "timer/tea"
+    name: String,
+    // Lazily initialize the GTK Label widget we'll update on each tick.
+    label: RefCell<Option<Label>>,
+    // Shared with the click handler
+    state: Rc<TimerState>,
+}
+
+struct TimerState {
+    duration: Option<Duration>,
+    on_expire: Option<String>,
+    // Time counted before the current run
+    elapsed: Cell<Duration>,
+    // When the current run started; `None` while paused
+    running_since: Cell<Option<Instant>>,
+}
+
+impl TimerItem {
+    pub fn new(name: &str, cfg: &TimerConfig) -> Result<Self> {
+        cfg.validate(name)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            label: RefCell::new(None),
+            state: Rc::new(TimerState {
+                duration: cfg.duration_secs.map(Duration::from_secs),
+                on_expire: cfg.on_expire.clone(),
+                elapsed: Cell::new(Duration::ZERO),
+                running_since: Cell::new(None),
+            }),
+        })
+    }
+}
+
+impl TimerState {
+    // Time counted so far, including the current run
+    fn elapsed(&self) -> Duration {
+        let running = self
+            .running_since
+            .get()
+            .map_or(Duration::ZERO, |since| since.elapsed());
+        self.elapsed.get() + running
+    }
+
+    fn toggle(&self) {
+        match self.running_since.take() {
+            Some(since) => self.elapsed.set(self.elapsed.get() + since.elapsed()),
+            None if self.expired() => {}
+            None => self.running_since.set(Some(Instant::now())),
+        }
+    }
+
+    fn reset(&self) {
+        self.running_since.set(None);
+        self.elapsed.set(Duration::ZERO);
+    }
+
+    fn expired(&self) -> bool {
+        self.duration
+            .is_some_and(|duration| self.elapsed() >= duration)
+    }
+
+    // Seconds to show: left for a countdown, elapsed for a stopwatch.
+    // Stops a countdown and runs `on_expire` when it reaches zero.
+    fn tick(&self) -> u64 {
+        let Some(duration) = self.duration else {
+            return self.elapsed().as_secs();
+        };
+        if self.running_since.get().is_some() && self.expired() {
+            self.running_since.set(None);
+            self.elapsed.set(duration);
+            if let Some(cmd) = &self.on_expire {
+                spawn_command(cmd);
+            }
+        }
+        // Round up, so the display reads 00:00 only once time is up
+        let left = duration.saturating_sub(self.elapsed());
+        left.as_secs() + u64::from(left.subsec_nanos() > 0)
+    }
+}
+
+// `secs` as `MM:SS`; minutes keep counting past 59
+pub fn fmt_mmss(secs: u64) -> String {
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+fn update_label(label: &Label, state: &TimerState) {
+    label.set_text(&fmt_mmss(state.tick()));
+    for (class, on) in [
+        ("running", state.running_since.get().is_some()),
+        ("expired", state.expired()),
+    ] {
+        if on {
+            label.add_css_class(class);
+        } else {
+            label.remove_css_class(class);
+        }
+    }
+}
+
+impl Item for TimerItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("timer-label");
+        update_label(&label, &self.state);
+
+        // Button 0 makes the gesture listen to every mouse button
+        let gesture = GestureClick::new();
+        gesture.set_button(0);
+        let state = self.state.clone();
+        let clicked = label.clone();
+        gesture.connect_pressed(move |gesture, _, _, _| {
+            match gesture.current_button() {
+                BUTTON_LEFT => state.toggle(),
+                BUTTON_RIGHT => state.reset(),
+                _ => return,
+            }
+            update_label(&clicked, &state);
+        });
+        container.add_controller(gesture);
+
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Ticking is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(Duration::from_secs(1))
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            update_label(label, &self.state);
+        }
+    }
+
+    fn always_update(&self) -> bool {
+        // The countdown's `on_expire` shouldn't wait for the bar to show
+        self.state.on_expire.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TimerConfig, TimerItem, fmt_mmss};
+
+    #[test]
+    fn formats_minutes_and_seconds() {
+        assert_eq!(fmt_mmss(0), "00:00");
+        assert_eq!(fmt_mmss(59), "00:59");
+        assert_eq!(fmt_mmss(60), "01:00");
+        assert_eq!(fmt_mmss(25 * 60), "25:00");
+        assert_eq!(fmt_mmss(100 * 60 + 1), "100:01");
+    }
+
+    #[test]
+    fn counts_only_while_running() {
+        let cfg = TimerConfig {
+            duration_secs: Some(300),
+            ..Default::default()
+        };
+        let item = TimerItem::new("timer", &cfg).unwrap();
+        assert_eq!(item.state.tick(), 300);
+
+        item.state.toggle();
+        assert!(item.state.running_since.get().is_some());
+        item.state.toggle();
+        assert!(item.state.running_since.get().is_none());
+        assert_eq!(item.state.tick(), 300);
+
+        item.state.reset();
+        assert!(!item.state.expired());
+
+        let zero = TimerConfig {
+            duration_secs: Some(0),
+            ..Default::default()
+        };
+        assert!(TimerItem::new("timer", &zero).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+// src/core/items/vpn.rs
+//
+// A status-bar item showing whether a VPN is up,
+// updating every `refresh_secs` seconds.
+//
+// A VPN is considered up while one of the configured tunnel interfaces
+// (e.g. OpenVPN's `tun0` or WireGuard's `wg0`) exists in `/proc/net/dev`.
+
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Image, Label, Widget};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::warn;
+
+// Overrides the network device list, mainly for tests
+pub const PROC_NET_DEV_ENV: &str = "PROC_NET_DEV";
+const PROC_NET_DEV: &str = "/proc/net/dev";
+const ICON_UP: &[&str] = &["network-vpn-symbolic", "changes-prevent-symbolic"];
+const ICON_DOWN: &[&str] = &["network-vpn-disabled-symbolic", "changes-allow-symbolic"];
+
+// Settings for the `vpn` item
+#[derive(Debug, Deserialize, Clone)]
+pub struct VpnConfig {
+    // Interfaces that mean a VPN is up, checked in order
+    #[serde(default = "default_interfaces")]
+    pub interfaces: Vec<String>,
+    // How often (in seconds) to check; defaults to the global
+    // `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for VpnConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)?;
+        if self.interfaces.is_empty() {
+            bail!("{name}: interfaces must not be empty");
+        }
+        Ok(())
+    }
+}
+
+fn default_interfaces() -> Vec<String> {
+    vec!["tun0".to_string(), "wg0".to_string()]
+}
+
+// VpnItem shows a lock icon and the tunnel's name while a VPN is up
+pub struct VpnItem {
+    path: PathBuf,
+    interfaces: Vec<String>,
+    // How often to check
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+}
+
+impl VpnItem {
+    pub fn new(cfg: &VpnConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("vpn")?;
+        let refresh =
+            item_refresh_interval("vpn", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        let path = std::env::var_os(PROC_NET_DEV_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(PROC_NET_DEV));
+
+        Ok(Self {
+            path,
+            interfaces: cfg.interfaces.clone(),
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &VpnConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Names of the interfaces in a `/proc/net/dev` dump
+pub fn net_devices(raw: &str) -> Vec<&str> {
+    // The first two lines are headers; each device line is `name: stats`
+    raw.lines()
+        .skip(2)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, _)| name.trim())
+        .collect()
+}
+
+// The first of `interfaces` that exists in `/proc/net/dev` at `path`
+pub fn active_vpn(path: &Path, interfaces: &[String]) -> Result<Option<String>> {
+    let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+    let devices = net_devices(&raw);
+    Ok(interfaces
+        .iter()
+        .find(|wanted| devices.contains(&wanted.as_str()))
+        .cloned())
+}
+
+// Show the VPN state on the icon and label
+fn update_widgets(image: &Image, label: &Label, path: &Path, interfaces: &[String]) {
+    match active_vpn(path, interfaces) {
+        Ok(Some(name)) => {
+            clear_error(label);
+            layout::set_icon(image, ICON_UP);
+            label.set_text(&name);
+            label.set_visible(true);
+            image.set_tooltip_text(Some(&format!("VPN up ({name})")));
+        }
+        Ok(None) => {
+            clear_error(label);
+            layout::set_icon(image, ICON_DOWN);
+            label.set_visible(false);
+            image.set_tooltip_text(Some("VPN down"));
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to check VPN state");
+            layout::set_icon(image, ICON_DOWN);
+            label.set_visible(true);
+            show_error(label, "VPN N/A", &e);
+        }
+    }
+}
+
+impl Item for VpnItem {
+    fn name(&self) -> &str {
+        "vpn"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("vpn-label");
+
+        // Set initial state
+        update_widgets(&image, &label, &self.path, &self.interfaces);
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Polling is driven by the shared scheduler via update()
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some((image, label)) = self.widgets.borrow().as_ref() {
+            update_widgets(image, label, &self.path, &self.interfaces);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{active_vpn, net_devices};
+    use std::fs;
+
+    const NET_DEV: &str = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 1843922   12876    0    0    0     0          0         0  1843922   12876    0    0    0     0       0          0
+wlp3s0: 912837465  701254    0    0    0     0          0         0 48123991  311209    0    0    0     0       0          0
+   wg0:   48210     312    0    0    0     0          0         0    91024     418    0    0    0     0       0          0
+";
+
+    #[test]
+    fn lists_devices() {
+        assert_eq!(net_devices(NET_DEV), ["lo", "wlp3s0", "wg0"]);
+    }
+
+    #[test]
+    fn finds_the_first_configured_tunnel() {
+        let path = std::env::temp_dir().join(format!("panel-rs-net-dev-{}", std::process::id()));
+        fs::write(&path, NET_DEV).unwrap();
+
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            active_vpn(&path, &names(&["tun0", "wg0"]))
+                .unwrap()
+                .as_deref(),
+            Some("wg0")
+        );
+        assert_eq!(active_vpn(&path, &names(&["tun0", "ppp0"])).unwrap(), None);
+
+        fs::remove_file(&path).unwrap();
+        assert!(active_vpn(&path, &names(&["wg0"])).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+// src/core/items/weather/item.rs
+//
+// A status-bar item showing the current weather,
+// re-fetched every `refresh_secs` seconds (15 minutes by default).
+//
+// Fetching happens on a worker thread so the GTK main loop never waits
+// on the network.
+
+use super::report::{Unit, Weather, parse_report, wttr_url};
+use crate::core::config::{ItemCommon, ValidateConfig, check_refresh_secs, item_refresh_interval};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::http::http_get;
+use crate::core::utils::status::{clear_error, show_error};
+use crate::core::utils::tolerant::ErrorTolerant;
+use anyhow::Result;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Settings for the `weather` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct WeatherConfig {
+    // City or place name; empty lets wttr.in locate you by IP
+    #[serde(default)]
+    pub location: String,
+    // How often (in seconds) to re-fetch; defaults to 900. The global
+    // `refresh_secs` is far too frequent for a web service.
+    pub refresh_secs: Option<f64>,
+    #[serde(default)]
+    pub unit: Unit,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for WeatherConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+const DEFAULT_REFRESH_SECS: f64 = 900.0;
+// Give up on a fetch after this long
+const FETCH_TIMEOUT_SECS: u32 = 10;
+
+// WeatherItem shows e.g. `☀ 18°C` with the condition in its tooltip
+pub struct WeatherItem {
+    url: String,
+    unit: Unit,
+    // How often to re-fetch
+    refresh: Duration,
+    // Lazily initialize the GTK Label widget we'll update on each fetch.
+    label: RefCell<Option<Label>>,
+    // Shared with in-flight fetches
+    state: Rc<FetchState>,
+}
+
+// State shared between scheduler ticks and in-flight fetches
+struct FetchState {
+    // A fetch is still in flight; skip ticks until it finishes
+    busy: Cell<bool>,
+    // Last good report, shown while the network is down
+    weather: RefCell<ErrorTolerant<Weather>>,
+}
+
+impl WeatherItem {
+    pub fn new(cfg: &WeatherConfig) -> Result<Self> {
+        cfg.validate("weather")?;
+        let refresh =
+            item_refresh_interval("weather", cfg.refresh_secs.unwrap_or(DEFAULT_REFRESH_SECS))?;
+
+        Ok(Self {
+            url: wttr_url(&cfg.location),
+            unit: cfg.unit,
+            refresh,
+            label: RefCell::new(None),
+            state: Rc::new(FetchState {
+                busy: Cell::new(false),
+                // Any cached reading beats N/A, however many fetches fail
+                weather: RefCell::new(ErrorTolerant::new(u32::MAX)),
+            }),
+        })
+    }
+}
+
+impl Item for WeatherItem {
+    fn name(&self) -> &str {
+        "weather"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the Label
+        let label = {
+            let mut slot = self.label.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(Label::new(None));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("weather-label");
+
+        container.append(&label);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Fetch right away rather than waiting a whole interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        if let Some(label) = self.label.borrow().as_ref() {
+            spawn_fetch(label, &self.url, self.unit, &self.state);
+        }
+    }
+}
+
+// Fetch the report on a worker thread and show it back on the GTK main
+// thread. Does nothing while a previous fetch is still going.
+fn spawn_fetch(label: &Label, url: &str, unit: Unit, state: &Rc<FetchState>) {
+    if state.busy.replace(true) {
+        return;
+    }
+
+    let label = label.clone();
+    let url = url.to_string();
+    let state = state.clone();
+    glib::spawn_future_local(async move {
+        let fetch_url = url.clone();
+        let result = gio::spawn_blocking(move || http_get(&fetch_url, FETCH_TIMEOUT_SECS))
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("fetch thread panicked")))
+            .and_then(|json| parse_report(&json, unit));
+
+        if let Err(e) = &result {
+            warn!(url = %url, error = %e, "Failed to fetch weather");
+        }
+        match state.weather.borrow_mut().record(result) {
+            Ok(weather) => {
+                clear_error(&label);
+                label.set_text(&weather.label());
+                label.set_tooltip_text(Some(&weather.condition));
+            }
+            Err(e) => show_error(&label, "Weather N/A", &e),
+        }
+        state.busy.set(false);
+    });
+}
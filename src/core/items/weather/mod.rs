@@ -0,0 +1,16 @@
+// src/core/items/weather/mod.rs
+//! A weather item showing the current conditions from wttr.in.
+//!
+//! ```toml
+//! items = ["weather", "clock"]
+//!
+//! [weather]
+//! location = "Turin"
+//! unit = "metric"
+//! ```
+
+mod item;
+mod report;
+
+pub use item::{WeatherConfig, WeatherItem};
+pub use report::{Unit, Weather, parse_report, symbol_for, wttr_url};
@@ -0,0 +1,174 @@
+// src/core/items/weather/report.rs
+//
+// Parsing wttr.in's `?format=j1` JSON into what the item displays.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+// Temperature unit to display
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Unit {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+// The current conditions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weather {
+    // Temperature in the configured unit, e.g. "18"
+    pub temp: String,
+    // Description, e.g. "Partly cloudy"
+    pub condition: String,
+    // WWO weather code, used to pick a symbol
+    pub code: u32,
+    pub unit: Unit,
+}
+
+impl Weather {
+    // Label text, e.g. "☀ 18°C"
+    pub fn label(&self) -> String {
+        let suffix = match self.unit {
+            Unit::Metric => "C",
+            Unit::Imperial => "F",
+        };
+        format!("{} {}°{suffix}", symbol_for(self.code), self.temp)
+    }
+}
+
+// Just the parts of the j1 report we read
+#[derive(Deserialize)]
+struct Report {
+    current_condition: Vec<CurrentCondition>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CurrentCondition {
+    #[serde(rename = "temp_C")]
+    temp_c: String,
+    #[serde(rename = "temp_F")]
+    temp_f: String,
+    weather_code: String,
+    weather_desc: Vec<Description>,
+}
+
+#[derive(Deserialize)]
+struct Description {
+    value: String,
+}
+
+// The j1 report URL for `location`; empty means wttr.in's IP geolocation
+pub fn wttr_url(location: &str) -> String {
+    format!(
+        "https://wttr.in/{}?format=j1",
+        encode_location(location.trim())
+    )
+}
+
+// `location` as one URL path segment: spaces become `+` as wttr.in
+// expects, and everything but unreserved ASCII is percent-encoded
+fn encode_location(location: &str) -> String {
+    let mut encoded = String::with_capacity(location.len());
+    for byte in location.bytes() {
+        match byte {
+            b' ' => encoded.push('+'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte))
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+// Extract the current conditions from a j1 report
+pub fn parse_report(json: &str, unit: Unit) -> Result<Weather> {
+    let report: Report = serde_json::from_str(json).context("Parsing wttr.in report")?;
+    let current = report
+        .current_condition
+        .into_iter()
+        .next()
+        .context("wttr.in report has no current conditions")?;
+
+    let code = current
+        .weather_code
+        .parse()
+        .with_context(|| format!("Invalid weather code {:?}", current.weather_code))?;
+    Ok(Weather {
+        temp: match unit {
+            Unit::Metric => current.temp_c,
+            Unit::Imperial => current.temp_f,
+        },
+        condition: current
+            .weather_desc
+            .into_iter()
+            .next()
+            .map(|d| d.value.trim().to_string())
+            .unwrap_or_default(),
+        code,
+        unit,
+    })
+}
+
+// A symbol for a WWO weather code, as used by wttr.in
+pub fn symbol_for(code: u32) -> &'static str {
+    match code {
+        113 => "☀",
+        116 => "⛅",
+        119 | 122 => "☁",
+        143 | 248 | 260 => "🌫",
+        200 | 386 | 389 | 392 | 395 => "⛈",
+        179 | 182 | 185 | 227 | 230 | 317 | 320 | 323 | 326 | 329 | 332 | 335 | 338 | 350 | 362
+        | 365 | 368 | 371 | 374 | 377 => "❄",
+        _ => "🌧",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Unit, parse_report, symbol_for, wttr_url};
+
+    const REPORT: &str = r#"{
+        "current_condition": [{
+            "FeelsLikeC": "17",
+            "temp_C": "18",
+            "temp_F": "64",
+            "weatherCode": "116",
+            "weatherDesc": [{"value": "Partly cloudy "}]
+        }],
+        "nearest_area": []
+    }"#;
+
+    #[test]
+    fn parses_current_conditions() {
+        let weather = parse_report(REPORT, Unit::Metric).unwrap();
+        assert_eq!(weather.temp, "18");
+        assert_eq!(weather.condition, "Partly cloudy");
+        assert_eq!(weather.label(), "⛅ 18°C");
+
+        let weather = parse_report(REPORT, Unit::Imperial).unwrap();
+        assert_eq!(weather.label(), "⛅ 64°F");
+    }
+
+    #[test]
+    fn rejects_reports_without_conditions() {
+        assert!(parse_report(r#"{"current_condition": []}"#, Unit::Metric).is_err());
+        assert!(parse_report("Unknown location", Unit::Metric).is_err());
+    }
+
+    #[test]
+    fn builds_urls_and_symbols() {
+        assert_eq!(wttr_url("New York"), "https://wttr.in/New+York?format=j1");
+        assert_eq!(wttr_url(""), "https://wttr.in/?format=j1");
+        assert_eq!(
+            wttr_url("Tom & Jerry?#/1"),
+            "https://wttr.in/Tom+%26+Jerry%3F%23%2F1?format=j1"
+        );
+        assert_eq!(wttr_url("Zürich"), "https://wttr.in/Z%C3%BCrich?format=j1");
+        assert_eq!(symbol_for(113), "☀");
+        assert_eq!(symbol_for(338), "❄");
+        assert_eq!(symbol_for(296), "🌧");
+    }
+}
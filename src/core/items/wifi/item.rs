@@ -0,0 +1,236 @@
+// src/core/items/wifi/item.rs
+//
+// A status-bar item showing Wi-Fi signal strength,
+// updating every `refresh_secs` seconds.
+//
+// The SSID comes from `iwgetid -r`, so reads happen on a worker thread
+// to keep a slow command from stalling the GTK main loop.
+
+use super::wireless::{WirelessLink, icon_for, parse_wireless, select_link};
+use crate::core::config::{
+    DEFAULT_REFRESH_SECS, IconPosition, ItemCommon, ItemDisplay, ValidateConfig,
+    check_refresh_secs, item_refresh_interval,
+};
+use crate::core::item::Item;
+use crate::core::layout;
+use crate::core::utils::status::{clear_error, show_error};
+use anyhow::{Context, Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Image, Label, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::warn;
+
+// Overrides the wireless statistics file, mainly for tests
+pub const PROC_NET_WIRELESS_ENV: &str = "PROC_NET_WIRELESS";
+const PROC_NET_WIRELESS: &str = "/proc/net/wireless";
+const ICON_OFFLINE: &str = "network-wireless-offline-symbolic";
+
+// Settings for the `wifi` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct WifiConfig {
+    // Wireless interface, e.g. "wlan0"; defaults to the first one listed
+    pub interface: Option<String>,
+    // Show the network name next to the icon
+    #[serde(default)]
+    pub show_ssid: bool,
+    // Show the link quality as a percentage
+    #[serde(default)]
+    pub show_percentage: bool,
+    // How often (in seconds) to re-read the link; defaults to the
+    // global `refresh_secs`
+    pub refresh_secs: Option<f64>,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for WifiConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        check_refresh_secs(name, self.refresh_secs)
+    }
+}
+
+// WifiItem shows a signal-strength icon with optional SSID and percentage
+pub struct WifiItem {
+    path: PathBuf,
+    interface: Option<String>,
+    show_ssid: bool,
+    show_percentage: bool,
+    // How often to re-read the link
+    refresh: Duration,
+    icon_position: IconPosition,
+    display: ItemDisplay,
+    // Lazily initialized widgets, updated on each tick
+    widgets: RefCell<Option<(Image, Label)>>,
+    // A read is still in flight; skip ticks until it finishes
+    busy: Rc<Cell<bool>>,
+}
+
+// One read: the link (None when disconnected) and its SSID
+type Reading = (Option<WirelessLink>, Option<String>);
+
+impl WifiItem {
+    // Fails without `/proc/net/wireless`, i.e. on kernels without
+    // wireless extensions, so the item is skipped.
+    pub fn new(cfg: &WifiConfig, default_refresh_secs: f64) -> Result<Self> {
+        cfg.validate("wifi")?;
+        let refresh =
+            item_refresh_interval("wifi", cfg.refresh_secs.unwrap_or(default_refresh_secs))?;
+
+        let path = std::env::var_os(PROC_NET_WIRELESS_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(PROC_NET_WIRELESS));
+        if !path.exists() {
+            bail!("No wireless statistics at {path:?}");
+        }
+
+        Ok(Self {
+            path,
+            interface: cfg.interface.clone(),
+            show_ssid: cfg.show_ssid,
+            show_percentage: cfg.show_percentage,
+            refresh,
+            icon_position: cfg.common.icon_position,
+            display: cfg.common.display,
+            widgets: RefCell::new(None),
+            busy: Rc::new(Cell::new(false)),
+        })
+    }
+
+    // `new` with the global default interval, for building the item
+    // without a loaded `Config`
+    pub fn with_defaults(cfg: &WifiConfig) -> Result<Self> {
+        Self::new(cfg, DEFAULT_REFRESH_SECS)
+    }
+}
+
+// Read the link and, if wanted, the SSID. Runs on a worker thread.
+fn read_link(path: &Path, interface: Option<&str>, show_ssid: bool) -> Result<Reading> {
+    let raw = fs::read_to_string(path).with_context(|| format!("Reading {path:?}"))?;
+    let Some(link) = select_link(parse_wireless(&raw)?, interface) else {
+        return Ok((None, None));
+    };
+
+    let ssid = if show_ssid {
+        ssid(&link.interface)
+            .inspect_err(|e| warn!(error = %e, "Failed to read SSID"))
+            .ok()
+    } else {
+        None
+    };
+    Ok((Some(link), ssid))
+}
+
+// The network `interface` is associated with, per `iwgetid`
+fn ssid(interface: &str) -> Result<String> {
+    let output = Command::new("iwgetid")
+        .args([interface, "--raw"])
+        .output()
+        .context("Running iwgetid")?;
+    if !output.status.success() {
+        bail!("iwgetid exited with {}", output.status);
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// The label text: SSID and/or percentage, space separated
+fn label_text(link: &WirelessLink, ssid: Option<&str>, show_percentage: bool) -> String {
+    let percent = show_percentage.then(|| format!("{}%", link.percent()));
+    [ssid.map(str::to_string), percent]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Item for WifiItem {
+    fn name(&self) -> &str {
+        "wifi"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = GtkBox::new(layout::orientation(), 4);
+
+        // Lazily initialize the icon and label
+        let (image, label) = {
+            let mut slot = self.widgets.borrow_mut();
+            if slot.is_none() {
+                *slot = Some((layout::icon(), Label::new(None)));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        label.add_css_class("wifi-label");
+        label.set_visible(false);
+
+        layout::append_icon_label(&container, &image, &label, self.icon_position, self.display);
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        // Show the link right away rather than after one interval
+        self.update();
+        Ok(())
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        Some(self.refresh)
+    }
+
+    fn update(&self) {
+        let Some((image, label)) = self.widgets.borrow().clone() else {
+            return;
+        };
+        if self.busy.replace(true) {
+            return;
+        }
+
+        let path = self.path.clone();
+        let interface = self.interface.clone();
+        let show_ssid = self.show_ssid;
+        let show_percentage = self.show_percentage;
+        let busy = self.busy.clone();
+        glib::spawn_future_local(async move {
+            let result =
+                gio::spawn_blocking(move || read_link(&path, interface.as_deref(), show_ssid))
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("read thread panicked")));
+
+            match result {
+                Ok((Some(link), ssid)) => {
+                    clear_error(&label);
+                    layout::set_icon(&image, &[icon_for(link.percent())]);
+                    let text = label_text(&link, ssid.as_deref(), show_percentage);
+                    label.set_visible(!text.is_empty());
+                    label.set_text(&text);
+                    let tooltip = format!(
+                        "{}: {}% ({} dBm)",
+                        link.interface,
+                        link.percent(),
+                        link.level
+                    );
+                    image.set_tooltip_text(Some(&tooltip));
+                    label.set_tooltip_text(Some(&tooltip));
+                }
+                Ok((None, _)) => {
+                    clear_error(&label);
+                    layout::set_icon(&image, &[ICON_OFFLINE]);
+                    label.set_visible(false);
+                    image.set_tooltip_text(Some("Disconnected"));
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to read Wi-Fi link");
+                    layout::set_icon(&image, &[ICON_OFFLINE]);
+                    label.set_visible(true);
+                    show_error(&label, "Wi-Fi N/A", &e);
+                }
+            }
+            busy.set(false);
+        });
+    }
+}
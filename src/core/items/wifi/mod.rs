@@ -0,0 +1,9 @@
+// src/core/items/wifi/mod.rs
+//! Shows Wi-Fi signal strength from `/proc/net/wireless`, optionally
+//! with the network name.
+
+mod item;
+mod wireless;
+
+pub use item::{PROC_NET_WIRELESS_ENV, WifiConfig, WifiItem};
+pub use wireless::{WirelessLink, icon_for, parse_wireless, select_link};
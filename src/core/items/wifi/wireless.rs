@@ -0,0 +1,133 @@
+// src/core/items/wifi/wireless.rs
+//
+// Parsing `/proc/net/wireless`, which looks like:
+//
+//   Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+//    face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+//   wlp3s0: 0000   54.  -56.  -256        0      0      0      0     33        0
+
+use crate::core::utils::icon::bucketed_name;
+use anyhow::{Context, Result};
+
+// Link quality is reported out of 70 by nearly every driver
+const MAX_QUALITY: f64 = 70.0;
+
+// One interface's line
+#[derive(Debug, Clone, PartialEq)]
+pub struct WirelessLink {
+    pub interface: String,
+    // Link quality, 0..=70
+    pub quality: f64,
+    // Signal level in dBm
+    pub level: f64,
+}
+
+impl WirelessLink {
+    // Link quality as a percentage
+    pub fn percent(&self) -> u8 {
+        (self.quality / MAX_QUALITY * 100.0)
+            .clamp(0.0, 100.0)
+            .round() as u8
+    }
+}
+
+// Every interface listed in `/proc/net/wireless`
+pub fn parse_wireless(raw: &str) -> Result<Vec<WirelessLink>> {
+    // The first two lines are headers
+    raw.lines()
+        .skip(2)
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<WirelessLink> {
+    let (interface, rest) = line
+        .split_once(':')
+        .with_context(|| format!("Missing interface in {line:?}"))?;
+    let mut columns = rest.split_whitespace().skip(1); // status
+
+    // Values updated since the last read end in '.'
+    let mut number = |what: &str| -> Result<f64> {
+        let raw = columns
+            .next()
+            .with_context(|| format!("Missing {what} in {line:?}"))?;
+        raw.trim_end_matches('.')
+            .parse()
+            .with_context(|| format!("Invalid {what} {raw:?}"))
+    };
+    Ok(WirelessLink {
+        interface: interface.trim().to_string(),
+        quality: number("link quality")?,
+        level: number("signal level")?,
+    })
+}
+
+// The link for `interface`, or the first one listed when unset
+pub fn select_link(links: Vec<WirelessLink>, interface: Option<&str>) -> Option<WirelessLink> {
+    match interface {
+        Some(name) => links.into_iter().find(|l| l.interface == name),
+        None => links.into_iter().next(),
+    }
+}
+
+// Signal icons by link quality percentage
+const ICONS: [(u8, &str); 4] = [
+    (25, "network-wireless-signal-weak-symbolic"),
+    (50, "network-wireless-signal-ok-symbolic"),
+    (75, "network-wireless-signal-good-symbolic"),
+    (u8::MAX, "network-wireless-signal-excellent-symbolic"),
+];
+
+// Symbolic icon for a link quality percentage
+pub fn icon_for(percent: u8) -> &'static str {
+    bucketed_name(percent, &ICONS, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{icon_for, parse_wireless, select_link};
+
+    const FIXTURE: &str = "\
+Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+ face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+wlp3s0: 0000   54.  -56.  -256        0      0      0      0     33        0
+ wlan1: 0000   20   -88   -256        0      0      0      0      0        0
+";
+
+    #[test]
+    fn parses_every_interface() {
+        let links = parse_wireless(FIXTURE).unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].interface, "wlp3s0");
+        assert_eq!(links[0].quality, 54.0);
+        assert_eq!(links[0].level, -56.0);
+        assert_eq!(links[0].percent(), 77);
+        assert_eq!(links[1].interface, "wlan1");
+        assert_eq!(links[1].percent(), 29);
+
+        // Headers only: no wireless interface is up
+        assert!(
+            parse_wireless(&FIXTURE[..FIXTURE.find("wlp3s0").unwrap()])
+                .unwrap()
+                .is_empty()
+        );
+        assert!(parse_wireless("h\nh\nwlan0: 0000 abc\n").is_err());
+    }
+
+    #[test]
+    fn selects_links_and_icons() {
+        let links = parse_wireless(FIXTURE).unwrap();
+        let first = select_link(links.clone(), None).unwrap();
+        assert_eq!(first.interface, "wlp3s0");
+        let named = select_link(links.clone(), Some("wlan1")).unwrap();
+        assert_eq!(
+            icon_for(named.percent()),
+            "network-wireless-signal-ok-symbolic"
+        );
+        assert_eq!(select_link(links, Some("wlan9")), None);
+
+        assert_eq!(icon_for(10), "network-wireless-signal-weak-symbolic");
+        assert_eq!(icon_for(100), "network-wireless-signal-excellent-symbolic");
+    }
+}
@@ -0,0 +1,71 @@
+// src/core/items/workspaces/backend.rs
+//
+// The compositor-independent side of the workspaces item. Every call
+// blocks on a socket and is made from a worker thread.
+
+use super::hyprland::Hyprland;
+use super::sway::SwayIpc;
+use anyhow::{Result, bail};
+use serde::Deserialize;
+use std::sync::Arc;
+
+// Which compositor to talk to
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendKind {
+    // Sway, or i3 through the same IPC protocol
+    #[default]
+    Sway,
+    Hyprland,
+    River,
+}
+
+impl BackendKind {
+    // Find the compositor's sockets. Nothing is opened yet.
+    pub fn connect(self) -> Result<Arc<dyn WorkspaceBackend>> {
+        Ok(match self {
+            BackendKind::Sway => Arc::new(SwayIpc::from_env()?),
+            BackendKind::Hyprland => Arc::new(Hyprland::from_env()?),
+            BackendKind::River => bail!("workspaces: the river backend isn't implemented yet"),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workspace {
+    pub name: String,
+    // Has keyboard focus
+    pub focused: bool,
+    // Shown on some output
+    pub visible: bool,
+    pub urgent: bool,
+}
+
+// A stream of compositor events
+pub trait Subscription: Send {
+    // Block until the workspaces may have changed
+    fn next_event(&mut self) -> Result<()>;
+}
+
+pub trait WorkspaceBackend: Send + Sync {
+    // All workspaces, in the compositor's order
+    fn list(&self) -> Result<Vec<Workspace>>;
+    // Switch to the workspace called `name`
+    fn focus(&self, name: &str) -> Result<()>;
+    // Start listening for workspace changes
+    fn subscribe(&self) -> Result<Box<dyn Subscription>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BackendKind;
+
+    #[test]
+    fn river_is_not_implemented() {
+        let err = BackendKind::River.connect().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "workspaces: the river backend isn't implemented yet"
+        );
+    }
+}
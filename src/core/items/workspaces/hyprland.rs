@@ -0,0 +1,197 @@
+// src/core/items/workspaces/hyprland.rs
+//
+// Hyprland IPC: one-shot requests on `.socket.sock` (prefixing a
+// request with `j/` asks for JSON) and an event stream of
+// `EVENT>>DATA` lines on `.socket2.sock`.
+
+use super::backend::{Subscription, Workspace, WorkspaceBackend};
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::env;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+// Events after which the workspace list may look different
+const WORKSPACE_EVENTS: &[&str] = &[
+    "workspace",
+    "workspacev2",
+    "focusedmon",
+    "createworkspace",
+    "createworkspacev2",
+    "destroyworkspace",
+    "destroyworkspacev2",
+    "moveworkspace",
+    "moveworkspacev2",
+    "renameworkspace",
+];
+
+pub struct Hyprland {
+    // Directory holding the instance's sockets
+    dir: PathBuf,
+}
+
+impl Hyprland {
+    pub fn from_env() -> Result<Self> {
+        let signature = env::var("HYPRLAND_INSTANCE_SIGNATURE")
+            .context("workspaces: HYPRLAND_INSTANCE_SIGNATURE is not set")?;
+        // Hyprland 0.40 moved its sockets from /tmp into the runtime dir
+        let runtime = env::var_os("XDG_RUNTIME_DIR")
+            .map(|dir| PathBuf::from(dir).join("hypr").join(&signature));
+        let dir = runtime
+            .filter(|dir| dir.exists())
+            .unwrap_or_else(|| PathBuf::from("/tmp/hypr").join(&signature));
+        Ok(Self { dir })
+    }
+
+    pub fn connect_events(&self) -> Result<HyprlandEvents> {
+        let path = self.dir.join(".socket2.sock");
+        let stream = UnixStream::connect(&path)
+            .with_context(|| format!("Connecting to {}", path.display()))?;
+        Ok(HyprlandEvents {
+            reader: BufReader::new(stream),
+        })
+    }
+
+    // Send one request and read the whole reply
+    pub fn request(&self, request: &str) -> Result<String> {
+        let path = self.dir.join(".socket.sock");
+        let mut stream = UnixStream::connect(&path)
+            .with_context(|| format!("Connecting to {}", path.display()))?;
+        stream.write_all(request.as_bytes())?;
+        let mut reply = String::new();
+        stream
+            .read_to_string(&mut reply)
+            .context("Reading Hyprland reply")?;
+        Ok(reply)
+    }
+}
+
+impl WorkspaceBackend for Hyprland {
+    fn list(&self) -> Result<Vec<Workspace>> {
+        parse_workspaces(&self.request("j/workspaces")?, &self.request("j/monitors")?)
+    }
+
+    fn focus(&self, name: &str) -> Result<()> {
+        let reply = self.request(&format!("dispatch workspace name:{name}"))?;
+        if reply.trim() != "ok" {
+            bail!("Hyprland dispatch failed: {}", reply.trim());
+        }
+        Ok(())
+    }
+
+    fn subscribe(&self) -> Result<Box<dyn Subscription>> {
+        Ok(Box::new(self.connect_events()?))
+    }
+}
+
+pub struct HyprlandEvents {
+    reader: BufReader<UnixStream>,
+}
+
+impl HyprlandEvents {
+    // The next event as (name, data)
+    pub fn next_line(&mut self) -> Result<(String, String)> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            bail!("Hyprland closed the event socket");
+        }
+        parse_event(&line).with_context(|| format!("Malformed event {line:?}"))
+    }
+}
+
+impl Subscription for HyprlandEvents {
+    fn next_event(&mut self) -> Result<()> {
+        loop {
+            let (name, _) = self.next_line()?;
+            if WORKSPACE_EVENTS.contains(&name.as_str()) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// Split an `EVENT>>DATA` line
+pub fn parse_event(line: &str) -> Option<(String, String)> {
+    let (name, data) = line.trim_end().split_once(">>")?;
+    Some((name.to_string(), data.to_string()))
+}
+
+#[derive(Deserialize)]
+struct HyprWorkspace {
+    id: i64,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Monitor {
+    focused: bool,
+    active_workspace: WorkspaceRef,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceRef {
+    id: i64,
+}
+
+// `j/workspaces` plus `j/monitors`, which say which workspaces are
+// shown and where the focus is. Sorted by id like Hyprland numbers them.
+pub fn parse_workspaces(workspaces: &str, monitors: &str) -> Result<Vec<Workspace>> {
+    let mut workspaces: Vec<HyprWorkspace> =
+        serde_json::from_str(workspaces).context("Parsing workspace list")?;
+    let monitors: Vec<Monitor> = serde_json::from_str(monitors).context("Parsing monitor list")?;
+    workspaces.sort_by_key(|ws| ws.id);
+
+    Ok(workspaces
+        .into_iter()
+        .map(|ws| {
+            let monitor = monitors.iter().find(|m| m.active_workspace.id == ws.id);
+            Workspace {
+                name: ws.name,
+                focused: monitor.is_some_and(|m| m.focused),
+                visible: monitor.is_some(),
+                urgent: false,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_event, parse_workspaces};
+
+    #[test]
+    fn parses_workspaces_with_monitors() {
+        let workspaces = r#"[
+            {"id": 3, "name": "3", "monitor": "DP-1", "windows": 1},
+            {"id": 1, "name": "1", "monitor": "DP-1", "windows": 2},
+            {"id": 2, "name": "web", "monitor": "HDMI-A-1", "windows": 0}
+        ]"#;
+        let monitors = r#"[
+            {"id": 0, "name": "DP-1", "focused": false, "activeWorkspace": {"id": 1, "name": "1"}},
+            {"id": 1, "name": "HDMI-A-1", "focused": true, "activeWorkspace": {"id": 2, "name": "web"}}
+        ]"#;
+        let list = parse_workspaces(workspaces, monitors).unwrap();
+        let names: Vec<&str> = list.iter().map(|ws| ws.name.as_str()).collect();
+        assert_eq!(names, ["1", "web", "3"]);
+        assert!(list[0].visible && !list[0].focused);
+        assert!(list[1].visible && list[1].focused);
+        assert!(!list[2].visible);
+
+        assert!(parse_workspaces("nope", monitors).is_err());
+    }
+
+    #[test]
+    fn splits_event_lines() {
+        assert_eq!(
+            parse_event("workspace>>2\n"),
+            Some(("workspace".into(), "2".into()))
+        );
+        assert_eq!(
+            parse_event("activewindow>>kitty,~>>zsh"),
+            Some(("activewindow".into(), "kitty,~>>zsh".into()))
+        );
+        assert_eq!(parse_event("garbage"), None);
+    }
+}
@@ -0,0 +1,186 @@
+// src/core/items/workspaces/item.rs
+//
+// A status-bar item with one button per workspace. It doesn't poll:
+// the list is re-read whenever the compositor reports a workspace
+// change, with the blocking socket reads on worker threads.
+
+use super::backend::{BackendKind, Subscription, Workspace, WorkspaceBackend};
+use crate::core::config::{ItemCommon, ValidateConfig};
+use crate::core::item::Item;
+use crate::core::layout;
+use anyhow::{Result, bail};
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Button, Widget, gio};
+use serde::Deserialize;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::Arc;
+use tracing::warn;
+
+// Settings for the `workspaces` item
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct WorkspacesConfig {
+    #[serde(default)]
+    pub backend: BackendKind,
+    #[serde(flatten)]
+    pub common: ItemCommon,
+}
+
+impl ValidateConfig for WorkspacesConfig {
+    fn validate(&self, name: &str) -> Result<()> {
+        if self.backend == BackendKind::River {
+            bail!("{name}: the river backend isn't implemented yet");
+        }
+        Ok(())
+    }
+}
+
+pub struct WorkspacesItem {
+    backend: Arc<dyn WorkspaceBackend>,
+    // Lazily initialized button box, refilled on every change
+    container: RefCell<Option<GtkBox>>,
+    // Bumped by start() and stop(); a listener exits once it's stale
+    generation: Rc<Cell<u64>>,
+}
+
+impl WorkspacesItem {
+    pub fn new(cfg: &WorkspacesConfig) -> Result<Self> {
+        cfg.validate("workspaces")?;
+        Ok(Self {
+            backend: cfg.backend.connect()?,
+            container: RefCell::new(None),
+            generation: Rc::new(Cell::new(0)),
+        })
+    }
+}
+
+// Replace the buttons with one per workspace
+fn render(container: &GtkBox, workspaces: &[Workspace], backend: &Arc<dyn WorkspaceBackend>) {
+    while let Some(child) = container.first_child() {
+        container.remove(&child);
+    }
+
+    for ws in workspaces {
+        let button = Button::with_label(&ws.name);
+        button.add_css_class("flat");
+        for (set, class) in [
+            (ws.focused, "focused"),
+            (ws.visible, "visible"),
+            (ws.urgent, "urgent"),
+        ] {
+            if set {
+                button.add_css_class(class);
+            }
+        }
+
+        // The change event that follows redraws the buttons
+        let (backend, name) = (backend.clone(), ws.name.clone());
+        button.connect_clicked(move |_| {
+            let (backend, name) = (backend.clone(), name.clone());
+            glib::spawn_future_local(async move {
+                let focused = gio::spawn_blocking(move || backend.focus(&name))
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("focus thread panicked")));
+                if let Err(e) = focused {
+                    warn!(error = %e, "Failed to switch workspace");
+                }
+            });
+        });
+        container.append(&button);
+    }
+}
+
+// Re-read the list after every event until the stream ends or the
+// item is stopped
+async fn listen(
+    container: GtkBox,
+    backend: Arc<dyn WorkspaceBackend>,
+    mut events: Box<dyn Subscription>,
+    generation: Rc<Cell<u64>>,
+    current: u64,
+) {
+    loop {
+        let listed = backend.clone();
+        match gio::spawn_blocking(move || listed.list())
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("list thread panicked")))
+        {
+            Ok(workspaces) => render(&container, &workspaces, &backend),
+            Err(e) => warn!(error = %e, "Failed to list workspaces"),
+        }
+
+        let Ok((returned, next)) = gio::spawn_blocking(move || {
+            let next = events.next_event();
+            (events, next)
+        })
+        .await
+        else {
+            warn!("Workspace event thread panicked");
+            return;
+        };
+        if generation.get() != current {
+            return;
+        }
+        if let Err(e) = next {
+            warn!(error = %e, "Workspace event stream ended");
+            return;
+        }
+        events = returned;
+    }
+}
+
+impl Item for WorkspacesItem {
+    fn name(&self) -> &str {
+        "workspaces"
+    }
+
+    fn widget(&self) -> Widget {
+        let container = {
+            let mut slot = self.container.borrow_mut();
+            if slot.is_none() {
+                *slot = Some(GtkBox::new(layout::orientation(), 0));
+            }
+            slot.as_ref().unwrap().clone()
+        };
+        container.add_css_class("workspaces");
+        container.upcast::<Widget>()
+    }
+
+    fn start(&self) -> Result<()> {
+        let Some(container) = self.container.borrow().clone() else {
+            return Ok(());
+        };
+        let events = self.backend.subscribe()?;
+
+        let current = self.generation.get() + 1;
+        self.generation.set(current);
+        glib::spawn_future_local(listen(
+            container,
+            self.backend.clone(),
+            events,
+            self.generation.clone(),
+            current,
+        ));
+        Ok(())
+    }
+
+    fn stop(&self) {
+        // The listener notices after its next event
+        self.generation.set(self.generation.get() + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkspacesConfig;
+    use crate::core::items::workspaces::BackendKind;
+
+    #[test]
+    fn backend_defaults_to_sway() {
+        let cfg: WorkspacesConfig = toml::from_str("").unwrap();
+        assert_eq!(cfg.backend, BackendKind::Sway);
+        let cfg: WorkspacesConfig = toml::from_str("backend = \"hyprland\"").unwrap();
+        assert_eq!(cfg.backend, BackendKind::Hyprland);
+        assert!(toml::from_str::<WorkspacesConfig>("backend = \"kwin\"").is_err());
+    }
+}
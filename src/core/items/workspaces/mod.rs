@@ -0,0 +1,12 @@
+// src/core/items/workspaces/mod.rs
+//! Shows the compositor's workspaces as buttons; clicking one switches
+//! to it. Each compositor is a `WorkspaceBackend`, picked with
+//! `backend = "sway" | "hyprland" | "river"`.
+
+mod backend;
+pub mod hyprland;
+mod item;
+pub mod sway;
+
+pub use backend::{BackendKind, Subscription, Workspace, WorkspaceBackend};
+pub use item::{WorkspacesConfig, WorkspacesItem};
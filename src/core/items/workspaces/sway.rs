@@ -0,0 +1,255 @@
+// src/core/items/workspaces/sway.rs
+//
+// Sway/i3 IPC: messages on the `$SWAYSOCK` Unix socket, framed as
+// "i3-ipc", a little-endian u32 payload length and message type, then
+// a JSON payload.
+
+use super::backend::{Subscription, Workspace, WorkspaceBackend};
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::env;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+
+const MAGIC: &[u8; 6] = b"i3-ipc";
+
+// Message types
+pub const RUN_COMMAND: u32 = 0;
+pub const GET_WORKSPACES: u32 = 1;
+pub const SUBSCRIBE: u32 = 2;
+pub const GET_BINDING_STATE: u32 = 12;
+
+pub struct SwayIpc {
+    socket: PathBuf,
+}
+
+impl SwayIpc {
+    pub fn from_env() -> Result<Self> {
+        let socket = env::var_os("SWAYSOCK")
+            .or_else(|| env::var_os("I3SOCK"))
+            .context("workspaces: neither SWAYSOCK nor I3SOCK is set")?;
+        Ok(Self {
+            socket: socket.into(),
+        })
+    }
+
+    pub fn connect(&self) -> Result<UnixStream> {
+        UnixStream::connect(&self.socket)
+            .with_context(|| format!("Connecting to {}", self.socket.display()))
+    }
+
+    // Send one message and wait for its reply
+    pub fn request(&self, kind: u32, payload: &str) -> Result<Vec<u8>> {
+        let mut stream = self.connect()?;
+        stream.write_all(&encode_message(kind, payload.as_bytes()))?;
+        let (_, reply) = read_message(&mut stream)?;
+        Ok(reply)
+    }
+}
+
+impl WorkspaceBackend for SwayIpc {
+    fn list(&self) -> Result<Vec<Workspace>> {
+        parse_workspaces(&self.request(GET_WORKSPACES, "")?)
+    }
+
+    fn focus(&self, name: &str) -> Result<()> {
+        let command = format!("workspace \"{}\"", name.replace('"', "\\\""));
+        check_command_reply(&self.request(RUN_COMMAND, &command)?)
+    }
+
+    fn subscribe(&self) -> Result<Box<dyn Subscription>> {
+        Ok(Box::new(subscribe(self, &["workspace"])?))
+    }
+}
+
+// Events from a subscribed connection
+pub struct SwayEvents {
+    stream: UnixStream,
+}
+
+impl SwayEvents {
+    // The next event's type and JSON payload
+    pub fn next_message(&mut self) -> Result<(u32, Vec<u8>)> {
+        read_message(&mut self.stream)
+    }
+}
+
+impl Subscription for SwayEvents {
+    fn next_event(&mut self) -> Result<()> {
+        self.next_message().map(|_| ())
+    }
+}
+
+// Subscribe a fresh connection to `events`, e.g. ["workspace"]
+pub fn subscribe(ipc: &SwayIpc, events: &[&str]) -> Result<SwayEvents> {
+    let mut stream = ipc.connect()?;
+    let payload = format!(
+        "[{}]",
+        events
+            .iter()
+            .map(|e| format!("\"{e}\""))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    stream.write_all(&encode_message(SUBSCRIBE, payload.as_bytes()))?;
+    let (_, reply) = read_message(&mut stream)?;
+    let reply: Success = serde_json::from_slice(&reply).context("Parsing subscribe reply")?;
+    if !reply.success {
+        bail!("Sway refused the {events:?} subscription");
+    }
+    Ok(SwayEvents { stream })
+}
+
+// Frame `payload` as an IPC message of type `kind`
+pub fn encode_message(kind: u32, payload: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(MAGIC.len() + 8 + payload.len());
+    message.extend_from_slice(MAGIC);
+    message.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    message.extend_from_slice(&kind.to_le_bytes());
+    message.extend_from_slice(payload);
+    message
+}
+
+// Read one framed message: (type, payload)
+pub fn read_message(reader: &mut impl Read) -> Result<(u32, Vec<u8>)> {
+    let mut header = [0u8; 14];
+    reader
+        .read_exact(&mut header)
+        .context("Reading IPC header")?;
+    if &header[..6] != MAGIC {
+        bail!("Bad IPC magic {:?}", &header[..6]);
+    }
+    let len = u32::from_le_bytes(header[6..10].try_into().unwrap());
+    let kind = u32::from_le_bytes(header[10..14].try_into().unwrap());
+    let mut payload = vec![0; len as usize];
+    reader
+        .read_exact(&mut payload)
+        .context("Reading IPC payload")?;
+    Ok((kind, payload))
+}
+
+#[derive(Deserialize)]
+struct Success {
+    success: bool,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SwayWorkspace {
+    name: String,
+    focused: bool,
+    visible: bool,
+    urgent: bool,
+}
+
+// GET_WORKSPACES reply
+pub fn parse_workspaces(json: &[u8]) -> Result<Vec<Workspace>> {
+    let workspaces: Vec<SwayWorkspace> =
+        serde_json::from_slice(json).context("Parsing workspace list")?;
+    Ok(workspaces
+        .into_iter()
+        .map(|ws| Workspace {
+            name: ws.name,
+            focused: ws.focused,
+            visible: ws.visible,
+            urgent: ws.urgent,
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct BindingState {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ModeEvent {
+    change: String,
+}
+
+// GET_BINDING_STATE reply: the current binding mode's name
+pub fn parse_binding_state(json: &[u8]) -> Result<String> {
+    let state: BindingState = serde_json::from_slice(json).context("Parsing binding state")?;
+    Ok(state.name)
+}
+
+// `mode` event payload: the mode just entered
+pub fn parse_mode_event(json: &[u8]) -> Result<String> {
+    let event: ModeEvent = serde_json::from_slice(json).context("Parsing mode event")?;
+    Ok(event.change)
+}
+
+// RUN_COMMAND reply: one result per command
+fn check_command_reply(json: &[u8]) -> Result<()> {
+    let results: Vec<Success> = serde_json::from_slice(json).context("Parsing command reply")?;
+    match results.into_iter().find(|r| !r.success) {
+        Some(failed) => bail!(
+            "Sway command failed: {}",
+            failed.error.as_deref().unwrap_or("unknown error")
+        ),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        GET_WORKSPACES, check_command_reply, encode_message, parse_binding_state, parse_mode_event,
+        parse_workspaces, read_message,
+    };
+    use std::io::Cursor;
+
+    #[test]
+    fn frames_round_trip() {
+        let message = encode_message(GET_WORKSPACES, b"[]");
+        assert_eq!(&message[..6], b"i3-ipc");
+        assert_eq!(message.len(), 14 + 2);
+
+        let (kind, payload) = read_message(&mut Cursor::new(message)).unwrap();
+        assert_eq!(kind, GET_WORKSPACES);
+        assert_eq!(payload, b"[]");
+
+        assert!(read_message(&mut Cursor::new(b"i3-ipx\0\0\0\0\0\0\0\0".to_vec())).is_err());
+        // Truncated payload
+        let mut short = encode_message(GET_WORKSPACES, b"[1, 2]");
+        short.truncate(17);
+        assert!(read_message(&mut Cursor::new(short)).is_err());
+    }
+
+    #[test]
+    fn parses_workspaces() {
+        let json = br#"[
+            {"num": 1, "name": "1", "visible": true, "focused": false, "urgent": false, "output": "DP-1"},
+            {"num": 2, "name": "2: web", "visible": true, "focused": true, "urgent": false, "output": "HDMI-A-1"},
+            {"num": 3, "name": "3", "visible": false, "focused": false, "urgent": true, "output": "DP-1"}
+        ]"#;
+        let workspaces = parse_workspaces(json).unwrap();
+        assert_eq!(workspaces.len(), 3);
+        assert_eq!(workspaces[1].name, "2: web");
+        assert!(workspaces[1].focused && workspaces[1].visible);
+        assert!(workspaces[2].urgent && !workspaces[2].visible);
+    }
+
+    #[test]
+    fn parses_mode_payloads() {
+        assert_eq!(
+            parse_binding_state(br#"{"name": "default"}"#).unwrap(),
+            "default"
+        );
+        assert_eq!(
+            parse_mode_event(br#"{"change": "resize", "pango_markup": false}"#).unwrap(),
+            "resize"
+        );
+        assert!(parse_mode_event(b"{}").is_err());
+    }
+
+    #[test]
+    fn reports_failed_commands() {
+        assert!(check_command_reply(br#"[{"success": true}]"#).is_ok());
+        let err = check_command_reply(br#"[{"success": false, "error": "No workspace"}]"#)
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "Sway command failed: No workspace");
+    }
+}
@@ -0,0 +1,153 @@
+// src/core/layout.rs
+//
+// Bar-wide layout that items follow while building their widgets.
+//
+// Set once by the `WindowManager` before any `widget()` call, so items can
+// lay out their own boxes along the bar without the orientation being
+// threaded through the `Item` trait.
+
+use super::config::{BarOrientation, IconPosition, ItemDisplay};
+use gtk4::gio::ThemedIcon;
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Image, Orientation, Widget};
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    // Widgets only exist on the GTK main thread, and so does this
+    static ORIENTATION: Cell<BarOrientation> = const { Cell::new(BarOrientation::Horizontal) };
+    // Icon size in logical pixels, from `[bar] icon_size`
+    static ICON_SIZE: Cell<u32> = const { Cell::new(16) };
+    // Tried after every item's own icon names, from `[bar] icon_fallbacks`
+    static ICON_FALLBACKS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+impl From<BarOrientation> for Orientation {
+    fn from(orientation: BarOrientation) -> Self {
+        match orientation {
+            BarOrientation::Horizontal => Orientation::Horizontal,
+            BarOrientation::Vertical => Orientation::Vertical,
+        }
+    }
+}
+
+pub fn set_orientation(orientation: BarOrientation) {
+    ORIENTATION.set(orientation);
+}
+
+// The direction items should lay out their contents in
+pub fn orientation() -> Orientation {
+    ORIENTATION.get().into()
+}
+
+pub fn is_vertical() -> bool {
+    ORIENTATION.get() == BarOrientation::Vertical
+}
+
+pub fn set_icon_size(pixels: u32) {
+    ICON_SIZE.set(pixels);
+}
+
+pub fn icon_size() -> i32 {
+    ICON_SIZE.get() as i32
+}
+
+// An empty icon at the configured size. Themed icons are looked up for
+// the monitor's scale factor by GTK itself, so they stay sharp on HiDPI.
+pub fn icon() -> Image {
+    let image = Image::new();
+    image.set_pixel_size(icon_size());
+    image
+}
+
+pub fn set_icon_fallbacks(names: Vec<String>) {
+    ICON_FALLBACKS.set(names);
+}
+
+// Show the first of `names` the icon theme has, then the global
+// fallbacks, instead of the broken-image placeholder for a missing icon
+pub fn set_icon(image: &Image, names: &[&str]) {
+    let candidates = ICON_FALLBACKS.with_borrow(|fallbacks| icon_candidates(names, fallbacks));
+    let candidates: Vec<&str> = candidates.iter().map(String::as_str).collect();
+    image.set_from_gicon(&ThemedIcon::from_names(&candidates));
+}
+
+// Append an item's icon and label to `container` on the configured
+// sides, leaving out whichever `display` hides. Hidden widgets can still
+// be updated; they just never show.
+pub fn append_icon_label(
+    container: &GtkBox,
+    icon: &impl IsA<Widget>,
+    label: &impl IsA<Widget>,
+    position: IconPosition,
+    display: ItemDisplay,
+) {
+    let [first, second] = position.order(
+        (icon.as_ref(), display.icon()),
+        (label.as_ref(), display.label()),
+    );
+    for (child, shown) in [first, second] {
+        if shown {
+            container.append(child);
+        }
+    }
+}
+
+// `names` followed by `fallbacks`, skipping repeats
+pub fn icon_candidates(names: &[&str], fallbacks: &[String]) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+    let all = names
+        .iter()
+        .copied()
+        .chain(fallbacks.iter().map(String::as_str));
+    for name in all {
+        if !candidates.iter().any(|c| c == name) {
+            candidates.push(name.to_string());
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        icon_candidates, icon_size, is_vertical, orientation, set_icon_size, set_orientation,
+    };
+    use crate::core::config::BarOrientation;
+    use gtk4::Orientation;
+
+    #[test]
+    fn follows_configured_orientation() {
+        assert_eq!(orientation(), Orientation::Horizontal);
+        set_orientation(BarOrientation::Vertical);
+        assert_eq!(orientation(), Orientation::Vertical);
+        assert!(is_vertical());
+        set_orientation(BarOrientation::Horizontal);
+    }
+
+    #[test]
+    fn follows_configured_icon_size() {
+        assert_eq!(icon_size(), 16);
+        set_icon_size(24);
+        assert_eq!(icon_size(), 24);
+        set_icon_size(16);
+    }
+
+    #[test]
+    fn icon_candidates_append_global_fallbacks() {
+        let fallbacks = vec!["battery".to_string(), "image-missing".to_string()];
+        assert_eq!(
+            icon_candidates(&["battery-full-symbolic", "battery-symbolic"], &fallbacks),
+            [
+                "battery-full-symbolic",
+                "battery-symbolic",
+                "battery",
+                "image-missing"
+            ]
+        );
+        // An item fallback that is also a global one is only tried once
+        assert_eq!(
+            icon_candidates(&["battery"], &fallbacks),
+            ["battery", "image-missing"]
+        );
+    }
+}
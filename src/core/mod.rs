@@ -1,8 +1,14 @@
 // src/core/mod.rs
 
+pub mod actions;
 pub mod config;
 pub mod config_loader;
 pub mod item;
 pub mod item_manager;
 pub mod items;
+pub mod layout;
+pub mod scheduler;
+pub mod systemd;
+pub mod utils;
+pub mod visibility;
 pub mod window;
@@ -0,0 +1,170 @@
+// src/core/scheduler.rs
+//
+// Drives every polling item from one GLib timeout per distinct interval.
+//
+// With one timer per item, eight 1s items woke the panel eight times a
+// second at unaligned offsets. Grouping them means each tick updates all
+// items sharing that interval at once.
+//
+// The first tick of each interval lands on the next whole wall-clock
+// second, so a clock showing seconds flips when the second does instead
+// of at some arbitrary offset into it.
+//
+// Each item updates in isolation: one that panics is logged and skipped
+// for that tick while the rest of its interval still updates.
+
+use super::item::Item;
+use super::item_manager::ItemManager;
+use chrono::{DateTime, Local, TimeZone, Timelike};
+use glib::source::{timeout_add_local, timeout_add_local_once};
+use glib::{ControlFlow, SourceId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::rc::Rc;
+use std::time::Duration;
+use tracing::{debug, error};
+
+// Owns the timers calling `Item::update()`
+#[derive(Default)]
+pub struct Scheduler {
+    // The pending source per interval, removed again by stop(). That is
+    // the aligning one-shot until it fires, then the periodic timer.
+    sources: Rc<RefCell<HashMap<Duration, SourceId>>>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Install one timer per interval used by `manager`'s items.
+    // Any timers from a previous call are removed first.
+    pub fn start(&self, manager: &Rc<ItemManager>) {
+        self.stop();
+
+        let delay = delay_to_next_second(&Local::now());
+        for (interval, indices) in manager.intervals() {
+            debug!(?interval, items = indices.len(), "Scheduling items");
+            let manager = manager.clone();
+            let sources = self.sources.clone();
+            let id = timeout_add_local_once(delay, move || {
+                let tick = move || update_items(manager.items(), &indices);
+                tick();
+                // Not timeout_add_seconds_local: GLib shifts those to its
+                // own per-process offset, undoing the alignment
+                let id = timeout_add_local(interval, move || {
+                    tick();
+                    ControlFlow::Continue
+                });
+                // The one-shot is done, so its id just gets replaced
+                sources.borrow_mut().insert(interval, id);
+            });
+            self.sources.borrow_mut().insert(interval, id);
+        }
+    }
+
+    // Remove all timers; items stop updating until the next start()
+    pub fn stop(&self) {
+        for (_, id) in self.sources.borrow_mut().drain() {
+            id.remove();
+        }
+    }
+}
+
+// Call `update()` on `items[i]` for every index. A panicking item is
+// logged by name and doesn't stop its siblings.
+pub fn update_items(items: &[Box<dyn Item>], indices: &[usize]) {
+    for &index in indices {
+        let item = &items[index];
+        if catch_unwind(AssertUnwindSafe(|| item.update())).is_err() {
+            error!(item = item.name(), "Item panicked while updating");
+        }
+    }
+}
+
+// Time from `now` until the next whole second
+pub fn delay_to_next_second<Tz: TimeZone>(now: &DateTime<Tz>) -> Duration {
+    // chrono reports a leap second as nanoseconds past 1_000_000_000
+    let into_second = now.nanosecond() % 1_000_000_000;
+    Duration::from_nanos(u64::from(1_000_000_000 - into_second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{delay_to_next_second, update_items};
+    use crate::core::item::Item;
+    use anyhow::Result;
+    use chrono::NaiveDate;
+    use gtk4::prelude::Cast;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    // Counts its updates, or panics in them when `panics` is set
+    struct Dummy {
+        panics: bool,
+        updates: Rc<Cell<u32>>,
+    }
+
+    impl Item for Dummy {
+        fn name(&self) -> &str {
+            "dummy"
+        }
+        fn widget(&self) -> gtk4::Widget {
+            // Never called here, so just a placeholder
+            gtk4::Box::new(gtk4::Orientation::Horizontal, 0).upcast()
+        }
+        fn start(&self) -> Result<()> {
+            Ok(())
+        }
+        fn update(&self) {
+            self.updates.set(self.updates.get() + 1);
+            if self.panics {
+                panic!("backend exploded");
+            }
+        }
+    }
+
+    #[test]
+    fn a_panicking_item_doesnt_stop_its_siblings() {
+        let updates = Rc::new(Cell::new(0));
+        let dummy = |panics| -> Box<dyn Item> {
+            Box::new(Dummy {
+                panics,
+                updates: updates.clone(),
+            })
+        };
+        let items = vec![dummy(false), dummy(true), dummy(false)];
+
+        update_items(&items, &[0, 1, 2]);
+        update_items(&items, &[0, 1, 2]);
+        assert_eq!(updates.get(), 6);
+    }
+
+    #[test]
+    fn delays_until_the_next_whole_second() {
+        let at = |nanos: u32| {
+            NaiveDate::from_ymd_opt(2025, 6, 1)
+                .unwrap()
+                .and_hms_nano_opt(12, 0, 59, nanos)
+                .unwrap()
+                .and_utc()
+        };
+        assert_eq!(
+            delay_to_next_second(&at(250_000_000)),
+            Duration::from_millis(750)
+        );
+        assert_eq!(
+            delay_to_next_second(&at(999_999_999)),
+            Duration::from_nanos(1)
+        );
+        // Exactly on a boundary: wait for the next one
+        assert_eq!(delay_to_next_second(&at(0)), Duration::from_secs(1));
+        // Leap second
+        assert_eq!(
+            delay_to_next_second(&at(1_400_000_000)),
+            Duration::from_millis(600)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+// src/core/systemd.rs
+//
+// Readiness notification for running as a systemd `Type=notify` user
+// service. systemd passes a datagram socket in `$NOTIFY_SOCKET`; we
+// send it `READY=1` once the bar is on screen. Outside systemd the
+// variable is unset and nothing is sent.
+
+use anyhow::{Context, Result};
+use std::env;
+use std::ffi::OsStr;
+use std::os::linux::net::SocketAddrExt;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::net::{SocketAddr, UnixDatagram};
+
+pub const NOTIFY_SOCKET_ENV: &str = "NOTIFY_SOCKET";
+
+// Tell systemd the panel is up. Returns whether anyone was listening.
+pub fn notify_ready() -> Result<bool> {
+    match env::var_os(NOTIFY_SOCKET_ENV) {
+        Some(socket) => notify(&socket, "READY=1").map(|()| true),
+        None => Ok(false),
+    }
+}
+
+// Send `state` to the notify socket at `socket`. A leading `@` names
+// a socket in the abstract namespace.
+pub fn notify(socket: &OsStr, state: &str) -> Result<()> {
+    let addr = match socket.as_bytes().strip_prefix(b"@") {
+        Some(name) => SocketAddr::from_abstract_name(name),
+        None => SocketAddr::from_pathname(socket),
+    }
+    .with_context(|| format!("Invalid {NOTIFY_SOCKET_ENV} {socket:?}"))?;
+
+    UnixDatagram::unbound()
+        .and_then(|sender| sender.send_to_addr(state.as_bytes(), &addr))
+        .with_context(|| format!("Notifying systemd at {socket:?}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::notify;
+    use std::os::unix::net::UnixDatagram;
+
+    #[test]
+    fn sends_state_to_the_socket() {
+        let path = std::env::temp_dir().join(format!("panel-rs-notify-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixDatagram::bind(&path).unwrap();
+
+        notify(path.as_os_str(), "READY=1").unwrap();
+        let mut buf = [0u8; 16];
+        let len = listener.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"READY=1");
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(notify(path.as_os_str(), "READY=1").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+// src/core/utils/bytes.rs
+//
+// Human-readable byte counts in binary units, shared by the items that
+// show sizes or rates so they all round the same way.
+
+const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+// Default number of decimals for items with a `precision` setting
+pub fn default_precision() -> usize {
+    1
+}
+
+// `bytes` in the largest unit that keeps the number at or above 1, e.g.
+// "1.5 GiB" with `precision` 1. Plain bytes are whole, so always "512 B".
+pub fn human_bytes(bytes: u64, precision: usize) -> String {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.precision$} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::human_bytes;
+
+    #[test]
+    fn picks_the_largest_unit() {
+        assert_eq!(human_bytes(0, 1), "0 B");
+        assert_eq!(human_bytes(1023, 1), "1023 B");
+        assert_eq!(human_bytes(1024, 1), "1.0 KiB");
+        assert_eq!(human_bytes(1536, 1), "1.5 KiB");
+        assert_eq!(human_bytes(3 << 29, 1), "1.5 GiB");
+    }
+
+    #[test]
+    fn honours_precision() {
+        assert_eq!(human_bytes(1536, 0), "2 KiB");
+        assert_eq!(human_bytes(1536, 3), "1.500 KiB");
+        // Precision doesn't apply to plain bytes
+        assert_eq!(human_bytes(512, 2), "512 B");
+    }
+
+    #[test]
+    fn handles_values_above_tib() {
+        assert_eq!(human_bytes(1 << 40, 1), "1.0 TiB");
+        assert_eq!(human_bytes(5 << 50, 1), "5.0 PiB");
+        assert_eq!(human_bytes(u64::MAX, 1), "16.0 EiB");
+    }
+}
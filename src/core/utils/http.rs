@@ -0,0 +1,28 @@
+// src/core/utils/http.rs
+//
+// Minimal HTTP GET for items that poll web services.
+//
+// Goes through `curl` rather than an HTTP client crate; callers run it
+// on a worker thread since it blocks for up to `timeout_secs`.
+
+use anyhow::{Context, Result, bail};
+use std::process::Command;
+
+// Download `url` and return the body. Fails on HTTP errors.
+pub fn http_get(url: &str, timeout_secs: u32) -> Result<String> {
+    let output = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--max-time", &timeout_secs.to_string()])
+        .arg(url)
+        .output()
+        .context("Running curl")?;
+
+    if !output.status.success() {
+        bail!(
+            "curl exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout).with_context(|| format!("{url} sent invalid UTF-8"))
+}
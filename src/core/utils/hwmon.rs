@@ -0,0 +1,301 @@
+// src/core/utils/hwmon.rs
+//
+// Discovery and reading of hwmon sensor inputs.
+//
+// Each `/sys/class/hwmon/hwmonN` directory belongs to one chip (its `name`
+// file) and exposes numbered inputs such as `temp1_input`, `fan1_input`
+// or `in0_input`, optionally with a matching `*_label`. Inputs are named
+// `<chip>-<label>`, e.g. `coretemp-Package id 0` or `thinkpad-fan1`.
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Overrides the hwmon root, mainly for tests
+pub const SYS_HWMON_BASE_ENV: &str = "SYS_HWMON_BASE";
+const SYS_HWMON_BASE: &str = "/sys/class/hwmon";
+
+// The kinds of hwmon inputs we know how to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    // `temp*_input`, millidegrees Celsius
+    Temp,
+    // `fan*_input`, RPM
+    Fan,
+    // `in*_input`, millivolts
+    Voltage,
+}
+
+impl InputKind {
+    // Every kind, in the order `--list-sensors` prints them
+    pub const ALL: [InputKind; 3] = [InputKind::Temp, InputKind::Fan, InputKind::Voltage];
+
+    // Human-readable name used in listings
+    pub fn label(self) -> &'static str {
+        match self {
+            InputKind::Temp => "temperature",
+            InputKind::Fan => "fan",
+            InputKind::Voltage => "voltage",
+        }
+    }
+
+    // File name prefix of this kind of input
+    fn prefix(self) -> &'static str {
+        match self {
+            InputKind::Temp => "temp",
+            InputKind::Fan => "fan",
+            InputKind::Voltage => "in",
+        }
+    }
+}
+
+// One readable hwmon input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HwmonInput {
+    // `<chip>-<label>`
+    pub name: String,
+    // Path of the `*_input` file
+    pub path: PathBuf,
+}
+
+impl HwmonInput {
+    // Read the raw integer value of the input
+    pub fn read_raw(&self) -> Result<i64> {
+        let raw = fs::read_to_string(&self.path)
+            .with_context(|| format!("Reading {}", self.path.display()))?;
+        raw.trim()
+            .parse()
+            .with_context(|| format!("Parsing {} value {:?}", self.name, raw.trim()))
+    }
+}
+
+// The hwmon root: `$SYS_HWMON_BASE` if set, `/sys/class/hwmon` otherwise
+pub fn hwmon_base() -> PathBuf {
+    std::env::var_os(SYS_HWMON_BASE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(SYS_HWMON_BASE))
+}
+
+// List every input of `kind` under `base`, sorted by name. A missing root
+// (common in containers and some VMs) is an error of its own; an existing
+// root without inputs just yields an empty list.
+pub fn discover(base: &Path, kind: InputKind) -> Result<Vec<HwmonInput>> {
+    if !base.exists() {
+        bail!(
+            "{base:?} doesn't exist, so this system exposes no hwmon sensors; \
+             set {SYS_HWMON_BASE_ENV} if they live elsewhere"
+        );
+    }
+    let entries =
+        fs::read_dir(base).with_context(|| format!("Listing hwmon devices in {base:?}"))?;
+
+    let mut inputs = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let chip = match fs::read_to_string(dir.join("name")) {
+            Ok(name) => name.trim().to_string(),
+            // Not a hwmon device (or unreadable): nothing to offer
+            Err(_) => continue,
+        };
+        inputs.extend(chip_inputs(&dir, &chip, kind));
+    }
+
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+// Whether the input `name` passes an item's `sensors` filter. An empty
+// filter or one containing "all" selects everything; otherwise entries
+// are globs where `*` matches any run of characters and `?` exactly one,
+// so `"coretemp-*"` survives hwmon renumbering between boots.
+pub fn sensor_selected(name: &str, filters: &[String]) -> bool {
+    filters.is_empty()
+        || filters
+            .iter()
+            .any(|filter| filter == "all" || glob_match(filter, name))
+}
+
+// Match `text` against a `*`/`?` wildcard pattern
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text index it was tried at
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            // Mismatch: let the last `*` swallow one more character
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// A listing of every input under `base`, grouped by kind, showing the
+// names items accept in their `sensors` setting
+pub fn sensor_report(base: &Path) -> Result<String> {
+    let mut report = String::new();
+    for kind in InputKind::ALL {
+        let inputs = discover(base, kind)?;
+        report.push_str(&format!("hwmon {}:\n", kind.label()));
+        if inputs.is_empty() {
+            report.push_str("  (none)\n");
+        }
+        for input in inputs {
+            report.push_str(&format!("  {}\n", input.name));
+        }
+    }
+    Ok(report)
+}
+
+// The inputs of `kind` exposed by a single hwmon device directory
+fn chip_inputs(dir: &Path, chip: &str, kind: InputKind) -> Vec<HwmonInput> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file = entry.file_name().into_string().ok()?;
+            let index = file
+                .strip_prefix(kind.prefix())?
+                .strip_suffix("_input")
+                .filter(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))?;
+
+            // Prefer the driver-provided label, e.g. "Package id 0"
+            let stem = format!("{}{index}", kind.prefix());
+            let label = fs::read_to_string(dir.join(format!("{stem}_label")))
+                .map(|l| l.trim().to_string())
+                .unwrap_or(stem);
+
+            Some(HwmonInput {
+                name: format!("{chip}-{label}"),
+                path: entry.path(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputKind, discover, glob_match, sensor_report, sensor_selected};
+    use std::fs;
+    use std::path::PathBuf;
+
+    // A throwaway hwmon tree under the system temp dir
+    fn fake_hwmon(test: &str) -> PathBuf {
+        let base = std::env::temp_dir().join(format!("panel-rs-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+
+        let chip = base.join("hwmon0");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "thinkpad\n").unwrap();
+        fs::write(chip.join("fan1_input"), "1200\n").unwrap();
+        fs::write(chip.join("temp1_input"), "45000\n").unwrap();
+        fs::write(chip.join("temp1_label"), "CPU\n").unwrap();
+        fs::write(chip.join("in0_input"), "12000\n").unwrap();
+        // Not inputs of any kind
+        fs::write(chip.join("fan1_min"), "0\n").unwrap();
+        fs::write(chip.join("intrusion0_alarm"), "0\n").unwrap();
+
+        // A directory without a `name` file is ignored
+        fs::create_dir_all(base.join("hwmon1")).unwrap();
+        base
+    }
+
+    #[test]
+    fn discovers_inputs_by_kind() {
+        let base = fake_hwmon("discover");
+
+        let fans = discover(&base, InputKind::Fan).unwrap();
+        assert_eq!(fans.len(), 1);
+        assert_eq!(fans[0].name, "thinkpad-fan1");
+        assert_eq!(fans[0].read_raw().unwrap(), 1200);
+
+        let temps = discover(&base, InputKind::Temp).unwrap();
+        assert_eq!(temps.len(), 1);
+        assert_eq!(temps[0].name, "thinkpad-CPU");
+
+        let volts = discover(&base, InputKind::Voltage).unwrap();
+        assert_eq!(volts.len(), 1);
+        assert_eq!(volts[0].name, "thinkpad-in0");
+
+        fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn reports_inputs_grouped_by_kind() {
+        let base = fake_hwmon("report");
+        assert_eq!(
+            sensor_report(&base).unwrap(),
+            "hwmon temperature:\n  thinkpad-CPU\nhwmon fan:\n  thinkpad-fan1\n\
+             hwmon voltage:\n  thinkpad-in0\n"
+        );
+        fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn filters_match_globs_and_all() {
+        let names = ["chipA-T1", "chipB-T2", "other-chip"];
+        let selected = |filters: &[&str]| -> Vec<&str> {
+            let filters: Vec<String> = filters.iter().map(|f| f.to_string()).collect();
+            names
+                .into_iter()
+                .filter(|name| sensor_selected(name, &filters))
+                .collect()
+        };
+
+        assert_eq!(selected(&["chip*"]), ["chipA-T1", "chipB-T2"]);
+        assert_eq!(selected(&["*chip*"]), names);
+        assert_eq!(selected(&["chip?-T2"]), ["chipB-T2"]);
+        assert_eq!(
+            selected(&["chipA-T1", "other-*"]),
+            ["chipA-T1", "other-chip"]
+        );
+        assert_eq!(selected(&["all"]), names);
+        assert_eq!(selected(&[]), names);
+        assert!(selected(&["chip"]).is_empty());
+    }
+
+    #[test]
+    fn globs_backtrack_over_stars() {
+        assert!(glob_match("*Package*", "coretemp-Package id 0"));
+        assert!(glob_match("a*b*c", "axxbyybc"));
+        assert!(glob_match("**", ""));
+        assert!(!glob_match("a*b", "axxc"));
+        assert!(!glob_match("?", ""));
+    }
+
+    #[test]
+    fn missing_base_is_an_error() {
+        let base = std::env::temp_dir().join("panel-rs-no-such-hwmon");
+        let err = discover(&base, InputKind::Fan).unwrap_err();
+        assert!(err.to_string().contains("doesn't exist"));
+    }
+
+    #[test]
+    fn empty_base_has_no_inputs() {
+        let base =
+            std::env::temp_dir().join(format!("panel-rs-empty-hwmon-{}", std::process::id()));
+        fs::create_dir_all(&base).unwrap();
+        assert!(discover(&base, InputKind::Fan).unwrap().is_empty());
+        fs::remove_dir_all(base).unwrap();
+    }
+}
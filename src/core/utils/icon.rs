@@ -0,0 +1,66 @@
+// src/core/utils/icon.rs
+//
+// Threshold-based icon selection.
+//
+// Items whose icon follows a 0-100 value (signal, load, volume) describe
+// their icons as a table of `(bound, name)` buckets in rising order
+// instead of each hand-writing the same comparisons.
+
+// The icon for `value`: the first bucket whose bound is above it, or the
+// last bucket past every bound. `override_name` wins when set, e.g. a
+// muted icon regardless of volume.
+pub fn bucketed_name<'a>(
+    value: u8,
+    buckets: &[(u8, &'a str)],
+    override_name: Option<&'a str>,
+) -> &'a str {
+    if let Some(name) = override_name {
+        return name;
+    }
+    buckets
+        .iter()
+        .find(|(bound, _)| value < *bound)
+        .or(buckets.last())
+        .map_or("", |(_, name)| name)
+}
+
+// A percentage as a bucket value, clamped to 0-100
+pub fn percent_value(percent: f64) -> u8 {
+    percent.clamp(0.0, 100.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bucketed_name, percent_value};
+
+    const BUCKETS: [(u8, &str); 3] = [(33, "low"), (66, "medium"), (100, "high")];
+
+    #[test]
+    fn picks_the_first_bucket_above_the_value() {
+        let cases = [
+            (0, "low"),
+            (32, "low"),
+            (33, "medium"),
+            (65, "medium"),
+            (66, "high"),
+            (100, "high"),
+            (255, "high"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(bucketed_name(value, &BUCKETS, None), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn override_wins_and_empty_tables_are_blank() {
+        assert_eq!(bucketed_name(50, &BUCKETS, Some("muted")), "muted");
+        assert_eq!(bucketed_name(50, &[], None), "");
+    }
+
+    #[test]
+    fn percentages_clamp_to_the_bucket_range() {
+        assert_eq!(percent_value(-5.0), 0);
+        assert_eq!(percent_value(32.9), 32);
+        assert_eq!(percent_value(250.0), 100);
+    }
+}
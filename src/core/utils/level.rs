@@ -0,0 +1,144 @@
+// src/core/utils/level.rs
+//
+// A `LevelBar` alternative to percentage text.
+//
+// Items showing a 0-100 value can let users pick `display = "text"`,
+// `"bar"` or `"both"`. The bar's offsets are placed at the warn and critical
+// thresholds, so its fill picks up the `normal`, `warn` and `critical`
+// CSS classes as the value climbs.
+
+use gtk4::LevelBar;
+use gtk4::prelude::*;
+use serde::Deserialize;
+
+// How an item presents its percentage
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LevelDisplay {
+    #[default]
+    Text,
+    Bar,
+    Both,
+}
+
+impl LevelDisplay {
+    pub fn text(self) -> bool {
+        matches!(self, LevelDisplay::Text | LevelDisplay::Both)
+    }
+
+    pub fn bar(self) -> bool {
+        matches!(self, LevelDisplay::Bar | LevelDisplay::Both)
+    }
+}
+
+// Settings shared by items that can draw a level bar. Items nest these
+// under a `level` key, so `display` doesn't clash with the flattened
+// `ItemCommon::display`.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct LevelConfig {
+    #[serde(default)]
+    pub display: LevelDisplay,
+    // Percentage above which the bar turns `warn`
+    #[serde(default = "default_warn")]
+    pub warn: f64,
+    // Percentage above which the bar turns `critical`
+    #[serde(default = "default_critical")]
+    pub critical: f64,
+}
+
+impl Default for LevelConfig {
+    fn default() -> Self {
+        LevelConfig {
+            display: LevelDisplay::default(),
+            warn: default_warn(),
+            critical: default_critical(),
+        }
+    }
+}
+
+fn default_warn() -> f64 {
+    70.0
+}
+
+fn default_critical() -> f64 {
+    90.0
+}
+
+// Named offsets for a 0-100 bar. GTK gives the fill the class of the
+// first offset at or above the value, so each name covers the range
+// ending at its offset.
+pub fn level_offsets(warn: f64, critical: f64) -> [(&'static str, f64); 3] {
+    let warn = warn.clamp(0.0, 100.0);
+    let critical = critical.clamp(warn, 100.0);
+    [("normal", warn), ("warn", critical), ("critical", 100.0)]
+}
+
+// The CSS class for `value` against the same thresholds, for items
+// colouring a label rather than drawing a bar
+pub fn level_class(value: f64, warn: f64, critical: f64) -> &'static str {
+    let [(_, warn), (_, critical), _] = level_offsets(warn, critical);
+    if value > critical {
+        "critical"
+    } else if value > warn {
+        "warn"
+    } else {
+        "normal"
+    }
+}
+
+// A 0-100 level bar colouring at `cfg`'s thresholds
+pub fn level_bar(cfg: &LevelConfig) -> LevelBar {
+    let bar = LevelBar::for_interval(0.0, 100.0);
+    bar.add_css_class("level");
+    // Drop GTK's default low/high/full offsets
+    for name in ["low", "high", "full"] {
+        bar.remove_offset_value(Some(name));
+    }
+    for (name, value) in level_offsets(cfg.warn, cfg.critical) {
+        bar.add_offset_value(name, value);
+    }
+    bar
+}
+
+// Show `percent` on the bar, clamped to its range
+pub fn set_level(bar: &LevelBar, percent: f64) {
+    bar.set_value(percent.clamp(0.0, 100.0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LevelConfig, LevelDisplay, level_class, level_offsets};
+
+    #[test]
+    fn offsets_follow_thresholds() {
+        assert_eq!(
+            level_offsets(70.0, 90.0),
+            [("normal", 70.0), ("warn", 90.0), ("critical", 100.0)]
+        );
+        // Critical below warn collapses the warn range
+        assert_eq!(
+            level_offsets(80.0, 50.0),
+            [("normal", 80.0), ("warn", 80.0), ("critical", 100.0)]
+        );
+    }
+
+    #[test]
+    fn classes_follow_thresholds() {
+        assert_eq!(level_class(10.0, 70.0, 90.0), "normal");
+        assert_eq!(level_class(70.0, 70.0, 90.0), "normal");
+        assert_eq!(level_class(75.0, 70.0, 90.0), "warn");
+        assert_eq!(level_class(95.0, 70.0, 90.0), "critical");
+    }
+
+    #[test]
+    fn parses_display_modes() {
+        let cfg: LevelConfig = toml::from_str("display = \"both\"\nwarn = 50").unwrap();
+        assert!(cfg.display.text() && cfg.display.bar());
+        assert_eq!(cfg.warn, 50.0);
+        assert_eq!(cfg.critical, 90.0);
+
+        let cfg: LevelConfig = toml::from_str("").unwrap();
+        assert_eq!(cfg.display, LevelDisplay::Text);
+        assert!(!cfg.display.bar());
+    }
+}
@@ -0,0 +1,24 @@
+// src/core/utils/markup.rs
+//
+// Setting label text that may be Pango markup, e.g.
+// `<span foreground='red'>12%</span>` or `<b>bold</b>`.
+
+use gtk4::{Label, pango};
+use tracing::warn;
+
+// Show `text` on the label, as markup when `markup` is set. Markup that
+// Pango can't parse (an unclosed tag, a stray `&`) is shown as plain
+// text rather than leaving the label blank.
+pub fn set_label_text(label: &Label, text: &str, markup: bool) {
+    if !markup {
+        label.set_text(text);
+        return;
+    }
+    match pango::parse_markup(text, '\0') {
+        Ok(_) => label.set_markup(text),
+        Err(e) => {
+            warn!(text, error = %e, "Invalid markup, showing it as plain text");
+            label.set_text(text);
+        }
+    }
+}
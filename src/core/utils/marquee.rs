@@ -0,0 +1,141 @@
+// src/core/utils/marquee.rs
+//
+// A label that scrolls text longer than `max_len` characters through a
+// fixed-width window, and shows shorter text as is.
+//
+// The animation holds only a weak reference to the label, so the
+// timeout ends by itself once the label is dropped.
+
+use glib::source::timeout_add_local;
+use glib::{ControlFlow, SourceId};
+use gtk4::Label;
+use gtk4::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+// Time between one-character steps
+const STEP: Duration = Duration::from_millis(300);
+// Blank space between the end of the text and its next start
+const GAP: &str = "   ";
+
+#[derive(Clone)]
+pub struct ScrollingLabel {
+    label: Label,
+    max_len: usize,
+    state: Rc<MarqueeState>,
+}
+
+#[derive(Default)]
+struct MarqueeState {
+    text: RefCell<String>,
+    offset: Cell<usize>,
+    // Running animation, if the text is too long to fit
+    source: RefCell<Option<SourceId>>,
+}
+
+impl ScrollingLabel {
+    // Scroll text on `label` once it's longer than `max_len` characters
+    pub fn new(label: &Label, max_len: usize) -> Self {
+        Self {
+            label: label.clone(),
+            max_len: max_len.max(1),
+            state: Rc::new(MarqueeState::default()),
+        }
+    }
+
+    // Show `text`, scrolling it if it doesn't fit. Setting the same text
+    // again keeps the current scroll position.
+    pub fn set_text(&self, text: &str) {
+        if *self.state.text.borrow() == text && self.state.source.borrow().is_some() {
+            return;
+        }
+        self.state.text.replace(text.to_string());
+        self.state.offset.set(0);
+
+        if text.chars().count() <= self.max_len {
+            self.stop();
+            self.label.set_text(text);
+            return;
+        }
+
+        self.label.set_text(&marquee_window(text, self.max_len, 0));
+        if self.state.source.borrow().is_none() {
+            let id = timeout_add_local(STEP, tick(&self.label, self.max_len, &self.state));
+            self.state.source.replace(Some(id));
+        }
+    }
+
+    // Stop scrolling, e.g. before showing an error on the label
+    pub fn stop(&self) {
+        if let Some(id) = self.state.source.take() {
+            id.remove();
+        }
+    }
+}
+
+// One animation step, or the end of the animation once the label is gone
+fn tick(
+    label: &Label,
+    width: usize,
+    state: &Rc<MarqueeState>,
+) -> impl FnMut() -> ControlFlow + use<> {
+    let label = label.downgrade();
+    let state = state.clone();
+    move || {
+        let Some(label) = label.upgrade() else {
+            // Returning Break removes the source; forget its id
+            state.source.take();
+            return ControlFlow::Break;
+        };
+        let offset = state.offset.get() + 1;
+        state.offset.set(offset);
+        label.set_text(&marquee_window(&state.text.borrow(), width, offset));
+        ControlFlow::Continue
+    }
+}
+
+// The `width` characters of `text` visible after scrolling `offset`
+// steps, wrapping around through a short gap. Text that fits is
+// returned whole.
+pub fn marquee_window(text: &str, width: usize, offset: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= width {
+        return text.to_string();
+    }
+
+    let cycle: Vec<char> = chars.into_iter().chain(GAP.chars()).collect();
+    cycle
+        .iter()
+        .cycle()
+        .skip(offset % cycle.len())
+        .take(width)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::marquee_window;
+
+    #[test]
+    fn short_text_is_static() {
+        assert_eq!(marquee_window("hello", 10, 3), "hello");
+        assert_eq!(marquee_window("hello", 5, 3), "hello");
+    }
+
+    #[test]
+    fn long_text_scrolls_and_wraps() {
+        assert_eq!(marquee_window("abcdefgh", 4, 0), "abcd");
+        assert_eq!(marquee_window("abcdefgh", 4, 2), "cdef");
+        // Into the gap and back to the start
+        assert_eq!(marquee_window("abcdefgh", 4, 6), "gh  ");
+        assert_eq!(marquee_window("abcdefgh", 4, 10), " abc");
+        // One full cycle is the text plus the gap
+        assert_eq!(marquee_window("abcdefgh", 4, 11), "abcd");
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        assert_eq!(marquee_window("ñandú café", 4, 1), "andú");
+    }
+}
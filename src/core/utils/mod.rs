@@ -0,0 +1,16 @@
+// src/core/utils/mod.rs
+//! Helpers shared by several items.
+
+pub mod bytes;
+pub mod http;
+pub mod hwmon;
+pub mod icon;
+pub mod level;
+pub mod markup;
+pub mod marquee;
+pub mod pactl;
+pub mod popover;
+pub mod reads;
+pub mod sparkline;
+pub mod status;
+pub mod tolerant;
@@ -0,0 +1,72 @@
+// src/core/utils/pactl.rs
+//
+// Querying PulseAudio (or PipeWire's pulse server) through `pactl`.
+//
+// The parsers are shared by items showing a sink or source; the
+// commands block, so run them off the GTK main thread.
+
+use anyhow::{Context, Result, bail};
+use std::process::Command;
+
+// Run `pactl <args>` and return its stdout
+pub fn pactl(args: &[&str]) -> Result<String> {
+    let output = Command::new("pactl")
+        .args(args)
+        .output()
+        .context("Running pactl")?;
+    if !output.status.success() {
+        bail!(
+            "pactl {} exited with {}: {}",
+            args.join(" "),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// `get-sink-mute` / `get-source-mute` output: `Mute: yes`
+pub fn parse_mute(output: &str) -> Result<bool> {
+    match output.trim().strip_prefix("Mute:").map(str::trim) {
+        Some("yes") => Ok(true),
+        Some("no") => Ok(false),
+        _ => bail!("Unexpected mute state {:?}", output.trim()),
+    }
+}
+
+// `get-sink-volume` / `get-source-volume` output, averaged over channels:
+//
+//   Volume: front-left: 39321 /  60% / -13.31 dB,   front-right: 39321 /  60% / -13.31 dB
+//           balance 0.00
+pub fn parse_volume(output: &str) -> Result<u32> {
+    let percents: Vec<u32> = output
+        .split_whitespace()
+        .filter_map(|word| word.strip_suffix('%'))
+        .map(|n| n.parse().with_context(|| format!("Invalid volume {n:?}%")))
+        .collect::<Result<_>>()?;
+    if percents.is_empty() {
+        bail!("No volume in {:?}", output.trim());
+    }
+    Ok(percents.iter().sum::<u32>() / percents.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_mute, parse_volume};
+
+    #[test]
+    fn parses_mute_state() {
+        assert!(parse_mute("Mute: yes\n").unwrap());
+        assert!(!parse_mute("Mute: no\n").unwrap());
+        assert!(parse_mute("Connection failure").is_err());
+    }
+
+    #[test]
+    fn averages_channel_volumes() {
+        let stereo = "Volume: front-left: 39321 /  60% / -13.31 dB,   front-right: 26214 /  40% / -23.87 dB\n        balance -0.33\n";
+        assert_eq!(parse_volume(stereo).unwrap(), 50);
+        let mono = "Volume: mono: 65536 / 100% / 0.00 dB\n";
+        assert_eq!(parse_volume(mono).unwrap(), 100);
+        assert!(parse_volume("").is_err());
+    }
+}
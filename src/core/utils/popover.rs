@@ -0,0 +1,69 @@
+// src/core/utils/popover.rs
+//
+// Click-to-open popovers: a list of details an item's label leaves out
+// (e.g. every fan when the label only fits their speeds), or any other
+// content an item builds on demand.
+
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, GestureClick, Label, Orientation, Popover, Widget};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Open a popover on left click of `widget`, filled with `lines()`.
+pub fn attach_detail_popover<F>(widget: &impl IsA<Widget>, lines: F)
+where
+    F: Fn() -> Vec<String> + 'static,
+{
+    attach_popover(widget, "details", move |_| detail_list(&lines()).upcast());
+}
+
+// Open a popover with class `css_class` on left click of `widget`,
+// filled with `content(popover)`.
+//
+// The popover is only built on the first click and its content is
+// rebuilt every time it opens. It is unparented again when `widget` is
+// destroyed, so it doesn't outlive the item. `content` should only keep
+// a weak reference to the popover it is given.
+pub fn attach_popover<F>(widget: &impl IsA<Widget>, css_class: &'static str, content: F)
+where
+    F: Fn(&Popover) -> Widget + 'static,
+{
+    let popover: Rc<RefCell<Option<Popover>>> = Rc::new(RefCell::new(None));
+
+    let gesture = GestureClick::new();
+    // Weak, so the widget's own controller doesn't keep it alive
+    let anchor = widget.as_ref().downgrade();
+    let slot = popover.clone();
+    gesture.connect_released(move |_, _, _, _| {
+        let Some(anchor) = anchor.upgrade() else {
+            return;
+        };
+        let mut slot = slot.borrow_mut();
+        let popover = slot.get_or_insert_with(|| {
+            let popover = Popover::new();
+            popover.add_css_class(css_class);
+            popover.set_parent(&anchor);
+            popover
+        });
+        popover.set_child(Some(&content(popover)));
+        popover.popup();
+    });
+    widget.add_controller(gesture);
+
+    widget.connect_destroy(move |_| {
+        if let Some(popover) = popover.borrow_mut().take() {
+            popover.unparent();
+        }
+    });
+}
+
+// One label per line, stacked vertically
+fn detail_list(lines: &[String]) -> GtkBox {
+    let list = GtkBox::new(Orientation::Vertical, 2);
+    for line in lines {
+        let label = Label::new(Some(line));
+        label.set_xalign(0.0);
+        list.append(&label);
+    }
+    list
+}
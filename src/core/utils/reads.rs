@@ -0,0 +1,74 @@
+// src/core/utils/reads.rs
+//
+// One-at-a-time worker reads for items that change state on click.
+//
+// Ticks that land while a read is in flight are simply skipped, as in the
+// other items. A read asked for right after a change can't be skipped the
+// same way: the read in flight may have started before the change, so
+// `ReadGate` queues one more for when it finishes.
+
+use std::cell::Cell;
+
+// Whether a read is in flight, and whether another is queued behind it
+#[derive(Debug, Default)]
+pub struct ReadGate {
+    busy: Cell<bool>,
+    pending: Cell<bool>,
+}
+
+impl ReadGate {
+    // Claim a read for a tick; false if one is already in flight
+    pub fn start(&self) -> bool {
+        !self.busy.replace(true)
+    }
+
+    // Claim a read after a change; when one is already in flight, queue
+    // another for when it finishes and return false
+    pub fn start_or_queue(&self) -> bool {
+        if self.start() {
+            return true;
+        }
+        self.pending.set(true);
+        false
+    }
+
+    // The read in flight finished. True if a queued read should start
+    // now, in which case the gate stays claimed for it.
+    pub fn finish(&self) -> bool {
+        if self.pending.replace(false) {
+            return true;
+        }
+        self.busy.set(false);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadGate;
+
+    #[test]
+    fn ticks_are_skipped_while_busy() {
+        let gate = ReadGate::default();
+        assert!(gate.start());
+        assert!(!gate.start());
+        // Nothing was queued, so the gate is free again
+        assert!(!gate.finish());
+        assert!(gate.start());
+    }
+
+    #[test]
+    fn a_read_after_a_change_is_queued_while_busy() {
+        let gate = ReadGate::default();
+        assert!(gate.start());
+        assert!(!gate.start_or_queue());
+        // Two changes during one read still need only one more read
+        assert!(!gate.start_or_queue());
+
+        // The queued read starts as the first finishes, keeping the gate
+        assert!(gate.finish());
+        assert!(!gate.start());
+        assert!(!gate.finish());
+        assert!(gate.start_or_queue());
+    }
+}
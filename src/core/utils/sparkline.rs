@@ -0,0 +1,150 @@
+// src/core/utils/sparkline.rs
+//
+// A tiny usage graph: the last few 0-100 samples drawn as a line in a
+// `DrawingArea`. The line takes the widget's CSS `color`.
+
+use gtk4::prelude::*;
+use gtk4::{DrawingArea, cairo};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+// Settings shared by items that can draw a sparkline, flattened into
+// their own config
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct SparklineConfig {
+    #[serde(default)]
+    pub sparkline: bool,
+    // Number of samples kept and drawn
+    #[serde(default = "default_history")]
+    pub history: usize,
+}
+
+impl Default for SparklineConfig {
+    fn default() -> Self {
+        SparklineConfig {
+            sparkline: false,
+            history: default_history(),
+        }
+    }
+}
+
+fn default_history() -> usize {
+    30
+}
+
+// Ring buffer of the most recent samples, oldest first
+#[derive(Debug, Clone)]
+pub struct History {
+    samples: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    // Add a sample, dropping the oldest once full
+    pub fn push(&mut self, sample: f64) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample.clamp(0.0, 100.0));
+    }
+
+    pub fn samples(&mut self) -> &[f64] {
+        self.samples.make_contiguous()
+    }
+}
+
+// Where each sample lands in a `width` x `height` area: spread evenly
+// from left to right with the newest at the right edge, 0 at the bottom
+// and 100 at the top
+pub fn sparkline_points(samples: &[f64], width: f64, height: f64) -> Vec<(f64, f64)> {
+    let step = match samples.len() {
+        0 | 1 => 0.0,
+        n => width / (n - 1) as f64,
+    };
+    let offset = if samples.len() == 1 { width } else { 0.0 };
+
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, sample)| {
+            let x = offset + i as f64 * step;
+            let y = height - sample.clamp(0.0, 100.0) / 100.0 * height;
+            (x, y)
+        })
+        .collect()
+}
+
+// Stroke the samples as a line with the current source colour
+pub fn draw_sparkline(ctx: &cairo::Context, samples: &[f64], width: f64, height: f64) {
+    let points = sparkline_points(samples, width, height);
+    let Some(&(x, y)) = points.first() else {
+        return;
+    };
+    ctx.set_line_width(1.0);
+    ctx.move_to(x, y);
+    for &(x, y) in &points[1..] {
+        ctx.line_to(x, y);
+    }
+    // Nothing to do about a failed stroke mid-frame
+    let _ = ctx.stroke();
+}
+
+// A drawing area redrawing `history` whenever it's queued
+pub fn sparkline_area(history: &Rc<RefCell<History>>) -> DrawingArea {
+    let area = DrawingArea::new();
+    area.add_css_class("sparkline");
+    area.set_content_width(40);
+
+    let history = history.clone();
+    area.set_draw_func(move |area, ctx, width, height| {
+        let color = area.style_context().color();
+        ctx.set_source_rgba(
+            color.red().into(),
+            color.green().into(),
+            color.blue().into(),
+            color.alpha().into(),
+        );
+        draw_sparkline(
+            ctx,
+            history.borrow_mut().samples(),
+            width.into(),
+            height.into(),
+        );
+    });
+    area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, sparkline_points};
+
+    #[test]
+    fn maps_samples_onto_the_area() {
+        assert_eq!(
+            sparkline_points(&[0.0, 50.0, 100.0], 40.0, 20.0),
+            [(0.0, 20.0), (20.0, 10.0), (40.0, 0.0)]
+        );
+        // Out-of-range samples stay inside the area
+        assert_eq!(sparkline_points(&[150.0], 40.0, 20.0), [(40.0, 0.0)]);
+        assert!(sparkline_points(&[], 40.0, 20.0).is_empty());
+    }
+
+    #[test]
+    fn history_keeps_latest_samples() {
+        let mut history = History::new(3);
+        for sample in [10.0, 20.0, 30.0, 40.0] {
+            history.push(sample);
+        }
+        assert_eq!(history.samples(), [20.0, 30.0, 40.0]);
+    }
+}
@@ -0,0 +1,48 @@
+// src/core/utils/status.rs
+//
+// Showing a failed backend read on a label.
+//
+// Items keep the label short ("Fan N/A") and put the actual error in the
+// tooltip, adding the `error` CSS class so the failed state can be styled.
+
+use gtk4::Label;
+use gtk4::prelude::*;
+
+// CSS class set on labels while their backend is failing
+pub const ERROR_CLASS: &str = "error";
+
+// Show `short` on the label with `err` (and its context chain) as tooltip
+pub fn show_error(label: &Label, short: &str, err: &anyhow::Error) {
+    label.set_text(short);
+    label.set_tooltip_text(Some(&error_tooltip(err)));
+    label.add_css_class(ERROR_CLASS);
+}
+
+// Undo `show_error` once the backend reads fine again
+pub fn clear_error(label: &Label) {
+    if label.has_css_class(ERROR_CLASS) {
+        label.remove_css_class(ERROR_CLASS);
+        label.set_tooltip_text(None);
+    }
+}
+
+// Tooltip text for `err`, outermost context first
+pub fn error_tooltip(err: &anyhow::Error) -> String {
+    format!("{err:#}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::error_tooltip;
+    use anyhow::Context;
+
+    #[test]
+    fn tooltip_includes_context_chain() {
+        let err = std::fs::read_to_string("/nonexistent/panel-rs")
+            .context("Reading fan1_input")
+            .unwrap_err();
+        let tooltip = error_tooltip(&err);
+        assert!(tooltip.starts_with("Reading fan1_input: "));
+        assert!(tooltip.len() > "Reading fan1_input: ".len());
+    }
+}
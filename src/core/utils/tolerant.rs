@@ -0,0 +1,105 @@
+// src/core/utils/tolerant.rs
+//
+// Riding out transient backend errors.
+//
+// A single failed read (e.g. a sysfs file briefly unavailable under load)
+// shouldn't flip an item to N/A. `ErrorTolerant` keeps showing the last good
+// value until more than `max_consecutive_errors` reads in a row have failed.
+
+use anyhow::Result;
+
+// Default for the per-item `max_consecutive_errors` setting
+pub const DEFAULT_MAX_CONSECUTIVE_ERRORS: u32 = 3;
+
+// Serde default for `max_consecutive_errors`
+pub fn default_max_consecutive_errors() -> u32 {
+    DEFAULT_MAX_CONSECUTIVE_ERRORS
+}
+
+// Tracks consecutive failures of a backend and its last good reading
+#[derive(Debug)]
+pub struct ErrorTolerant<T> {
+    max_consecutive_errors: u32,
+    fail_count: u32,
+    last_good: Option<T>,
+}
+
+impl<T> ErrorTolerant<T> {
+    pub fn new(max_consecutive_errors: u32) -> Self {
+        Self {
+            max_consecutive_errors,
+            fail_count: 0,
+            last_good: None,
+        }
+    }
+
+    // Feed one read and get back what to display: the new value on success,
+    // the last good one while failures are within budget, or the error once
+    // the budget is exceeded or there is nothing to fall back on.
+    pub fn record(&mut self, result: Result<T>) -> Result<&T> {
+        match result {
+            Ok(value) => {
+                self.fail_count = 0;
+                Ok(self.last_good.insert(value))
+            }
+            Err(e) => {
+                self.fail_count = self.fail_count.saturating_add(1);
+                match &self.last_good {
+                    Some(value) if self.fail_count <= self.max_consecutive_errors => Ok(value),
+                    _ => Err(e),
+                }
+            }
+        }
+    }
+
+    // Reads failed in a row so far
+    pub fn fail_count(&self) -> u32 {
+        self.fail_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorTolerant;
+    use anyhow::{Result, anyhow};
+
+    fn fail() -> Result<u32> {
+        Err(anyhow!("read failed"))
+    }
+
+    #[test]
+    fn alternating_errors_keep_last_good_value() {
+        let mut tolerant = ErrorTolerant::new(1);
+        assert_eq!(*tolerant.record(Ok(10)).unwrap(), 10);
+        assert_eq!(*tolerant.record(fail()).unwrap(), 10);
+        assert_eq!(*tolerant.record(Ok(20)).unwrap(), 20);
+        assert_eq!(*tolerant.record(fail()).unwrap(), 20);
+        assert_eq!(*tolerant.record(Ok(30)).unwrap(), 30);
+        assert_eq!(tolerant.fail_count(), 0);
+    }
+
+    #[test]
+    fn too_many_consecutive_errors_surface() {
+        let mut tolerant = ErrorTolerant::new(3);
+        tolerant.record(Ok(1)).unwrap();
+        for _ in 0..3 {
+            assert_eq!(*tolerant.record(fail()).unwrap(), 1);
+        }
+        assert!(tolerant.record(fail()).is_err());
+        assert_eq!(tolerant.fail_count(), 4);
+
+        // One good read resets the budget
+        assert_eq!(*tolerant.record(Ok(2)).unwrap(), 2);
+        assert_eq!(*tolerant.record(fail()).unwrap(), 2);
+    }
+
+    #[test]
+    fn errors_without_a_good_value_surface() {
+        let mut tolerant = ErrorTolerant::new(3);
+        assert!(tolerant.record(fail()).is_err());
+
+        let mut strict = ErrorTolerant::new(0);
+        strict.record(Ok(1)).unwrap();
+        assert!(strict.record(fail()).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+// src/core/visibility.rs
+//
+// Skipping polls while the bar can't be seen.
+//
+// `VisibleOnly` wraps a polling `Item` and drops its scheduler ticks
+// while the bar window isn't mapped (e.g. hidden by the compositor),
+// so reads and subprocesses don't run for nobody. The timer keeps
+// going; one catch-up update runs as soon as the window is mapped again.
+
+use super::config::ItemCommon;
+use super::item::Item;
+use anyhow::Result;
+use gtk4::Widget;
+use gtk4::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+pub struct VisibleOnly {
+    inner: Rc<dyn Item>,
+    // The item's root widget, once built
+    root: RefCell<Option<Widget>>,
+    // A tick was skipped; update once the window shows again
+    missed: Rc<Cell<bool>>,
+    // Whether we're listening for the window being mapped
+    watching: Cell<bool>,
+}
+
+impl VisibleOnly {
+    // Wrap `inner` if it polls, may skip ticks and `skip_when_hidden` is
+    // on, otherwise hand it back untouched
+    pub fn wrap(inner: Box<dyn Item>, common: &ItemCommon) -> Box<dyn Item> {
+        if !common.skip_when_hidden || inner.refresh_interval().is_none() || inner.always_update() {
+            return inner;
+        }
+        Box::new(VisibleOnly {
+            inner: Rc::from(inner),
+            root: RefCell::new(None),
+            missed: Rc::new(Cell::new(false)),
+            watching: Cell::new(false),
+        })
+    }
+}
+
+impl Item for VisibleOnly {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn widget(&self) -> Widget {
+        let widget = self.inner.widget();
+        *self.root.borrow_mut() = Some(widget.clone());
+        widget
+    }
+
+    fn start(&self) -> Result<()> {
+        self.inner.start()
+    }
+
+    fn refresh_interval(&self) -> Option<Duration> {
+        self.inner.refresh_interval()
+    }
+
+    fn update(&self) {
+        // Ask the window rather than the item's own widget: items hide
+        // themselves (e.g. a custom command printing nothing) and must
+        // keep polling to come back
+        let window = self.root.borrow().as_ref().and_then(|w| w.root());
+        let Some(window) = window.filter(|w| !w.is_mapped()) else {
+            self.inner.update();
+            return;
+        };
+
+        self.missed.set(true);
+        if !self.watching.replace(true) {
+            let (inner, missed) = (self.inner.clone(), self.missed.clone());
+            window.connect_map(move |_| {
+                if missed.replace(false) {
+                    inner.update();
+                }
+            });
+        }
+    }
+
+    fn always_update(&self) -> bool {
+        self.inner.always_update()
+    }
+
+    fn stop(&self) {
+        self.inner.stop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VisibleOnly;
+    use crate::core::config::ItemCommon;
+    use crate::core::item::Item;
+    use anyhow::Result;
+    use gtk4::prelude::Cast;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    struct Counting(Option<Duration>, Rc<Cell<u32>>);
+    impl Item for Counting {
+        fn name(&self) -> &str {
+            "counting"
+        }
+        fn widget(&self) -> gtk4::Widget {
+            // Never called here, so just a placeholder
+            gtk4::Box::new(gtk4::Orientation::Horizontal, 0).upcast()
+        }
+        fn start(&self) -> Result<()> {
+            Ok(())
+        }
+        fn refresh_interval(&self) -> Option<Duration> {
+            self.0
+        }
+        fn update(&self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    #[test]
+    fn updates_pass_through_before_the_widget_exists() {
+        let updates = Rc::new(Cell::new(0));
+        let item = VisibleOnly::wrap(
+            Box::new(Counting(Some(Duration::from_secs(1)), updates.clone())),
+            &ItemCommon::default(),
+        );
+        assert_eq!(item.name(), "counting");
+        assert_eq!(item.refresh_interval(), Some(Duration::from_secs(1)));
+        item.update();
+        item.update();
+        assert_eq!(updates.get(), 2);
+    }
+
+    #[test]
+    fn skip_when_hidden_defaults_on() {
+        assert!(ItemCommon::default().skip_when_hidden);
+        let common: ItemCommon = toml::from_str("skip_when_hidden = false").unwrap();
+        assert!(!common.skip_when_hidden);
+        let common: ItemCommon = toml::from_str("").unwrap();
+        assert!(common.skip_when_hidden);
+    }
+}
@@ -3,15 +3,23 @@ use anyhow::{Context, Result};
 use gtk4::gdk::Display;
 use gtk4::prelude::*;
 use gtk4::{
-    Application, ApplicationWindow, Box, CssProvider, Orientation,
-    STYLE_PROVIDER_PRIORITY_APPLICATION, style_context_add_provider_for_display,
+    Application, ApplicationWindow, Box, CssProvider, STYLE_PROVIDER_PRIORITY_APPLICATION,
+    style_context_add_provider_for_display,
 };
-use gtk4_layer_shell::{Edge, Layer, LayerShell};
+use gtk4_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
+use std::rc::Rc;
 
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use super::config::Config;
+use super::config::{BarLayer, BarPosition, BarWidth, Config, KeyboardInteractivity, LoadOptions};
 use super::item_manager::ItemManager;
+use super::layout;
+use super::scheduler::Scheduler;
+use super::systemd;
+
+// Signal numbers (the same on every Linux architecture)
+const SIGINT: i32 = 2;
+const SIGTERM: i32 = 15;
 
 // Manages the panel window lifecycle
 pub struct WindowManager {
@@ -42,12 +50,39 @@ impl WindowManager {
     }
 }
 
+// The connector name (e.g. "eDP-1") of the only monitor, if there's one
+fn single_monitor_connector() -> Option<String> {
+    let monitors = Display::default()?.monitors();
+    if monitors.n_items() != 1 {
+        return None;
+    }
+    let monitor = monitors.item(0)?.downcast::<gtk4::gdk::Monitor>().ok()?;
+    monitor.connector().map(String::from)
+}
+
+// Log which monitor the compositor put the bar on
+fn log_monitor(window: &ApplicationWindow) {
+    let monitor = window
+        .surface()
+        .and_then(|surface| surface.display().monitor_at_surface(&surface))
+        .and_then(|monitor| monitor.connector());
+    info!(
+        monitor = monitor.as_deref().unwrap_or("unknown"),
+        "Bar window mapped"
+    );
+}
+
 impl WindowManager {
     // Initialises GTK and configuration
     pub fn new() -> Result<Self> {
+        Self::with_options(&LoadOptions::default())
+    }
+
+    // Like `new`, but loads the config as described by `options`
+    pub fn with_options(options: &LoadOptions) -> Result<Self> {
         info!("Initialising WindowManager");
         // 1. Load and validate config
-        let config = Config::load().context("Loading application configuration")?;
+        let config = Config::load_with(options).context("Loading application configuration")?;
 
         // 2. (TODO) Load configuration from file
         info!(?config, "WindowManager initialised with config");
@@ -64,32 +99,72 @@ impl WindowManager {
 
         // Clone config so we can move it into the ItemManager
         let config = self._config.clone();
-        // Build the ItemManager from the config
-        let manager = ItemManager::load(&config);
+        // With several monitors the compositor picks one when the bar maps,
+        // so items only learn the output up front when there is just one
+        let monitor = single_monitor_connector();
+        // Build the ItemManager from the config.
+        // Shared between the activate and shutdown handlers.
+        let manager = Rc::new(ItemManager::load_on(&config, monitor.as_deref()));
         info!(
             num_items = manager.items().len(),
+            monitor = monitor.as_deref().unwrap_or("unknown"),
             "Loaded items from config"
         );
 
+        // Items read the bar orientation and icon size while building widgets
+        let bar = config.bar.clone();
+        layout::set_orientation(bar.orientation);
+        layout::set_icon_size(bar.icon_size);
+        layout::set_icon_fallbacks(bar.icon_fallbacks.clone());
+
+        // One timer per refresh interval drives every polling item
+        let scheduler = Rc::new(Scheduler::new());
+
         // 1. Create a GTK4 Application with a reverse-domain ID
         let app = Application::new(Some("com.nadirfasola.panel"), Default::default());
 
         // 2. When the app activates, build our panel window
+        let activate_manager = manager.clone();
+        let activate_scheduler = scheduler.clone();
         app.connect_activate(move |app| {
+            let manager = &activate_manager;
             // Create a window tied to the application
             let window = ApplicationWindow::new(app);
-            window.set_default_size(400, 30); // 400 x 30 px window
+            // 30 px thick; the length follows `[bar] width`, with -1 leaving
+            // it to the anchors or the items' natural size
+            let length = match bar.width {
+                BarWidth::Pixels(px) => px as i32,
+                BarWidth::Full | BarWidth::Auto => -1,
+            };
+            if layout::is_vertical() {
+                window.set_default_size(30, length);
+            } else {
+                window.set_default_size(length, 30);
+            }
             window.set_decorated(false); // remove titlebar
 
-            // Dock it with layer-shell at the bottom
+            // Dock it with layer-shell at the configured edge. The exclusive
+            // zone applies along the axis perpendicular to that edge.
             window.init_layer_shell();
-            window.set_layer(Layer::Top);
-            window.set_anchor(Edge::Bottom, true);
-            window.set_exclusive_zone(30);
+            // Lets compositor rules match the bar, e.g. for blur
+            window.set_namespace(Some("panel-rs"));
+            window.set_keyboard_mode(keyboard_mode_for(bar.keyboard_interactivity));
+            window.set_layer(layer_for(bar.layer));
+            window.set_anchor(edge_for(bar.position()), true);
+            if bar.width == BarWidth::Full {
+                for edge in bar.span_edges() {
+                    window.set_anchor(edge_for(edge), true);
+                }
+            }
+            for (edge, margin) in bar.margins() {
+                window.set_margin(edge_for(edge), margin);
+            }
+            window.set_exclusive_zone(bar.exclusive_zone.unwrap_or(30));
             window.set_widget_name("panel-window");
+            window.connect_map(log_monitor);
 
             // Create the bar's main container
-            let container = Box::new(Orientation::Horizontal, 0);
+            let container = Box::new(layout::orientation(), 0);
 
             // For each item, build its widget and add it
             for item in manager.items() {
@@ -111,11 +186,68 @@ impl WindowManager {
                     error!(item = item.name(), error = %e, "Failed to start item");
                 }
             }
+            activate_scheduler.start(manager);
+
+            // The bar is up: let a `Type=notify` service finish starting
+            match systemd::notify_ready() {
+                Ok(true) => info!("Notified systemd of readiness"),
+                Ok(false) => {}
+                Err(e) => warn!(error = %e, "Failed to notify systemd"),
+            }
         });
 
-        // 3. Run the GTK4 main loop
-        app.run();
+        // Stop every item's timers and handlers before the loop exits
+        app.connect_shutdown(move |_| {
+            scheduler.stop();
+            for item in manager.items() {
+                item.stop();
+            }
+        });
+
+        // Quit through the application on SIGTERM (`systemctl stop`) or
+        // SIGINT, so the shutdown handler above stops every item
+        for signal in [SIGINT, SIGTERM] {
+            let app = app.clone();
+            glib::unix_signal_add_local(signal, move || {
+                info!(signal, "Received signal, quitting");
+                app.quit();
+                glib::ControlFlow::Continue
+            });
+        }
+
+        // 3. Run the GTK4 main loop. main.rs has already parsed the
+        //    command line, and GApplication would reject our options
+        app.run_with_args::<&str>(&[]);
 
         Ok(())
     }
 }
+
+// The layer-shell edge for a configured bar position
+fn edge_for(position: BarPosition) -> Edge {
+    match position {
+        BarPosition::Top => Edge::Top,
+        BarPosition::Bottom => Edge::Bottom,
+        BarPosition::Left => Edge::Left,
+        BarPosition::Right => Edge::Right,
+    }
+}
+
+// The layer-shell layer for a configured one
+fn layer_for(layer: BarLayer) -> Layer {
+    match layer {
+        BarLayer::Background => Layer::Background,
+        BarLayer::Bottom => Layer::Bottom,
+        BarLayer::Top => Layer::Top,
+        BarLayer::Overlay => Layer::Overlay,
+    }
+}
+
+// The layer-shell keyboard mode for a configured interactivity
+fn keyboard_mode_for(interactivity: KeyboardInteractivity) -> KeyboardMode {
+    match interactivity {
+        KeyboardInteractivity::None => KeyboardMode::None,
+        KeyboardInteractivity::OnDemand => KeyboardMode::OnDemand,
+        KeyboardInteractivity::Exclusive => KeyboardMode::Exclusive,
+    }
+}
@@ -1,3 +1,5 @@
 // src/lib.rs
 
+pub mod cli;
 pub mod core;
+pub mod log_file;
@@ -0,0 +1,88 @@
+// src/log_file.rs
+//
+// Optional logging to a file, for running the panel in the background
+// where stderr goes nowhere useful.
+//
+// The file rolls over daily through tracing-appender: `<name>.<date>`
+// next to the configured path, with only the last few days kept so the
+// logs don't grow forever.
+
+use anyhow::{Context, Result, anyhow};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+// Path of the log file, when `--log-file` isn't given
+pub const LOG_FILE_ENV: &str = "PANEL_LOG_FILE";
+
+// How many daily files to keep, today's included
+pub const KEPT_LOG_FILES: usize = 7;
+
+// A daily rolling appender writing `<path>.<date>` files
+pub fn rolling_appender(path: &Path) -> Result<RollingFileAppender> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("Log file {} has no file name", path.display()))?;
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(name.to_string_lossy())
+        .max_log_files(KEPT_LOG_FILES)
+        .build(dir)
+        .with_context(|| format!("Opening log file {}", path.display()))
+}
+
+// The log file from `--log-file`, else from `$PANEL_LOG_FILE`
+pub fn log_file_path(flag: Option<PathBuf>, env: Option<OsString>) -> Option<PathBuf> {
+    flag.or_else(|| env.filter(|v| !v.is_empty()).map(PathBuf::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{log_file_path, rolling_appender};
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    #[test]
+    fn writes_dated_files_next_to_the_path() {
+        let dir = std::env::temp_dir().join(format!("panel-rs-log-{}", std::process::id()));
+        let path = dir.join("logs").join("panel.log");
+
+        let mut appender = rolling_appender(&path).unwrap();
+        writeln!(appender, "first line").unwrap();
+        appender.flush().unwrap();
+        drop(appender);
+
+        let files: Vec<_> = fs::read_dir(dir.join("logs"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(files.len(), 1);
+        let name = files[0].file_name().unwrap().to_string_lossy().into_owned();
+        assert!(name.starts_with("panel.log."), "{name}");
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), "first line\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_path_without_a_file_name() {
+        assert!(rolling_appender(&PathBuf::from("/")).is_err());
+    }
+
+    #[test]
+    fn flag_wins_over_env() {
+        let flag = Some(PathBuf::from("/tmp/a.log"));
+        assert_eq!(log_file_path(flag.clone(), Some("/tmp/b.log".into())), flag);
+        assert_eq!(
+            log_file_path(None, Some("/tmp/b.log".into())),
+            Some(PathBuf::from("/tmp/b.log"))
+        );
+        assert_eq!(log_file_path(None, Some("".into())), None);
+        assert_eq!(log_file_path(None, None), None);
+    }
+}
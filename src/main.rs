@@ -1,39 +1,103 @@
 // src/main.rs
 use anyhow::Result;
+use panel_rs::cli::{Args, USAGE};
+use panel_rs::core::config::{Config, LoadOptions};
+use panel_rs::core::item_manager::ItemManager;
+use panel_rs::core::utils::hwmon::{hwmon_base, sensor_report};
 use panel_rs::core::window::WindowManager;
+use panel_rs::log_file::{LOG_FILE_ENV, log_file_path, rolling_appender};
 use std::panic;
-use tracing::info;
+use std::path::PathBuf;
+use tracing::{error, info, warn};
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::{EnvFilter, fmt};
 
 fn main() -> Result<()> {
+    let args = Args::from_env()?;
+    if args.help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+    if args.version {
+        println!("panel-rs {}", env!("CARGO_PKG_VERSION"));
+        return Ok(());
+    }
+    if args.list_sensors {
+        print!("{}", sensor_report(&hwmon_base())?);
+        return Ok(());
+    }
+    if args.validate {
+        // Print OK or the error and exit without starting GTK
+        std::process::exit(match validate(args.config) {
+            Ok(()) => {
+                println!("OK");
+                0
+            }
+            Err(e) => {
+                eprintln!("Invalid config: {e:#}");
+                1
+            }
+        });
+    }
+
     // Initialize tracing subscriber for formatted, leveled logs
     //
     // - `EnvFilter::from_default_env()` rads RUST_LOG
     // - `fmt::layer()` prints to stderr with timestamps and levels
+    // - with `--log-file` or PANEL_LOG_FILE, a second layer writes the
+    //   same lines, without colours, to a daily rolling file
     let filter_layer =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("panel_rs=info"));
+    let log_file = log_file_path(args.log_file, std::env::var_os(LOG_FILE_ENV));
+    let appender = log_file.as_deref().map(rolling_appender).transpose();
+    // The guard flushes the file writer's thread; hold it until exit
+    let (file_layer, _log_guard, log_error) = match appender {
+        Ok(Some(appender)) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = fmt::layer().with_ansi(false).with_writer(writer);
+            (Some(layer), Some(guard), None)
+        }
+        Ok(None) => (None, None, None),
+        Err(e) => (None, None, Some(e)),
+    };
     tracing_subscriber::registry()
         .with(filter_layer)
         .with(fmt::layer())
+        .with(file_layer)
         .init();
+    // A log file that can't be opened shouldn't keep the panel from starting
+    if let Some(e) = log_error {
+        warn!("Logging to stderr only: {e:#}");
+    }
 
     // Example log to verify it's working
     info!("Starting panel_rs v{}", env!("CARGO_PKG_VERSION"));
 
     // Set a custom panic hook.
-    // This replaces the default panic handler.
-    // Print panic info and immediately terminate the process
-    // with exit code 1.
-    panic::set_hook(Box::new(|info| {
+    // Log the panic, then chain to the default hook. The hook must not
+    // exit: the scheduler catches a panicking item's unwind and keeps
+    // the rest of the bar running.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
         // info carries panic information + location
-        eprintln!("Application panicked: {info}");
-        std::process::exit(1);
+        error!("Application panicked: {info}");
+        default_hook(info);
     }));
 
+    // On first run, give the user a config file to edit
+    let options = LoadOptions {
+        path: args.config,
+        write_default_if_missing: true,
+    };
     // Build the window manager (initialises GTK, loads config)
-    let mut wm = WindowManager::new()?;
+    let mut wm = WindowManager::with_options(&options)?;
     // Run the UI loop
     wm.run()?;
     Ok(())
 }
+
+// Load the config the same way the panel would and run the extra checks
+fn validate(config_path: Option<PathBuf>) -> Result<()> {
+    let config = Config::load_from(config_path)?;
+    ItemManager::validate(&config)
+}
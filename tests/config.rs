@@ -0,0 +1,215 @@
+// tests/config.rs
+
+use panel_rs::core::config::{
+    BarLayer, BarOrientation, BarPosition, BarWidth, Config, IconPosition, ItemCommon, ItemDisplay,
+    KeyboardInteractivity, item_refresh_interval, refresh_interval, write_starter_config,
+};
+use panel_rs::core::items::custom::CustomConfig;
+use panel_rs::core::utils::level::LevelDisplay;
+use std::fs;
+use std::time::Duration;
+
+#[test]
+fn load_from_uses_given_path() {
+    let dir = std::env::temp_dir().join(format!("panel-rs-config-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("alt.toml");
+    fs::write(&path, "items = [\"spacer\", \"clock\"]\nrefresh_secs = 7\n").unwrap();
+
+    let cfg = Config::load_from(Some(path)).unwrap();
+    assert_eq!(cfg.items, ["spacer", "clock"]);
+    assert_eq!(cfg.refresh_secs, 7.0);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn load_from_missing_path_fails() {
+    let path = std::env::temp_dir().join("panel-rs-no-such-config.toml");
+    assert!(Config::load_from(Some(path)).is_err());
+}
+
+#[test]
+fn starter_config_is_loadable_and_never_overwritten() {
+    let dir = std::env::temp_dir().join(format!("panel-rs-starter-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config.toml");
+
+    write_starter_config(&path).unwrap();
+    let cfg = Config::load_from(Some(path.clone())).unwrap();
+    assert_eq!(cfg.items, ["clock"]);
+
+    // An existing file is left alone
+    fs::write(&path, "items = []\n").unwrap();
+    assert!(write_starter_config(&path).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "items = []\n");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn load_files_falls_back_to_embedded_default() {
+    let missing = std::env::temp_dir().join("panel-rs-no-such-dir");
+    let cfg =
+        Config::load_files(&missing.join("default.toml"), &missing.join("config.toml")).unwrap();
+    assert_eq!(cfg.items, ["clock"]);
+    assert_eq!(cfg.refresh_secs, 1.0);
+}
+
+#[test]
+fn bar_orientation_picks_matching_edge() {
+    let cfg: Config = toml::from_str("items = []\n[bar]\norientation = \"vertical\"\n").unwrap();
+    assert_eq!(cfg.bar.orientation, BarOrientation::Vertical);
+    assert_eq!(cfg.bar.position(), BarPosition::Left);
+    assert!(cfg.bar.validate().is_ok());
+    // `[bar]` is not mistaken for an item table
+    assert!(!cfg.modules.contains_key("bar"));
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nposition = \"left\"\n").unwrap();
+    assert!(cfg.bar.validate().is_err());
+}
+
+#[test]
+fn refresh_secs_become_millisecond_intervals() {
+    assert_eq!(refresh_interval("x", 1.0).unwrap(), Duration::from_secs(1));
+    assert_eq!(
+        refresh_interval("x", 0.5).unwrap(),
+        Duration::from_millis(500)
+    );
+    assert_eq!(
+        refresh_interval("x", 2.0004).unwrap(),
+        Duration::from_secs(2)
+    );
+
+    let err = refresh_interval("procs", 0.0).unwrap_err();
+    assert!(err.to_string().starts_with("procs: refresh_secs"));
+    assert!(refresh_interval("x", f64::NAN).is_err());
+    assert!(refresh_interval("x", f64::INFINITY).is_err());
+
+    // An item's own 0 is clamped; anything else out of range still fails
+    assert_eq!(
+        item_refresh_interval("mic", 0.0).unwrap(),
+        Duration::from_secs(1)
+    );
+    assert!(item_refresh_interval("mic", 0.01).is_err());
+    assert!(item_refresh_interval("mic", -1.0).is_err());
+    assert!(item_refresh_interval("mic", f64::NAN).is_err());
+
+    // Whole seconds still parse from plain integers
+    let cfg: Config = toml::from_str("items = []\nrefresh_secs = 3\n").unwrap();
+    assert_eq!(cfg.refresh_secs, 3.0);
+    let cfg: Config = toml::from_str("items = []\nrefresh_secs = 0.25\n").unwrap();
+    assert_eq!(cfg.refresh_secs, 0.25);
+}
+
+#[test]
+fn layer_and_margins() {
+    let cfg: Config = toml::from_str("items = []\n").unwrap();
+    assert_eq!(cfg.bar.layer, BarLayer::Top);
+    assert!(cfg.bar.margins().iter().all(|(_, margin)| *margin == 0));
+
+    let cfg: Config =
+        toml::from_str("items = []\n[bar]\nlayer = \"overlay\"\nmargin_left = 8\n").unwrap();
+    assert_eq!(cfg.bar.layer, BarLayer::Overlay);
+    assert!(cfg.bar.margins().contains(&(BarPosition::Left, 8)));
+    assert!(cfg.bar.validate().is_ok());
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nmargin_top = -4\n").unwrap();
+    let err = cfg.bar.validate().unwrap_err();
+    assert!(err.to_string().contains("margin_top"));
+}
+
+#[test]
+fn bar_width_is_full_auto_or_pixels() {
+    let cfg: Config = toml::from_str("items = []\n").unwrap();
+    assert_eq!(cfg.bar.width, BarWidth::Full);
+    assert_eq!(
+        cfg.bar.span_edges(),
+        [BarPosition::Left, BarPosition::Right]
+    );
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nwidth = \"auto\"\n").unwrap();
+    assert_eq!(cfg.bar.width, BarWidth::Auto);
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nwidth = 640\n").unwrap();
+    assert_eq!(cfg.bar.width, BarWidth::Pixels(640));
+    assert!(cfg.bar.validate().is_ok());
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nwidth = 0\n").unwrap();
+    assert!(cfg.bar.validate().is_err());
+    assert!(toml::from_str::<Config>("items = []\n[bar]\nwidth = \"wide\"\n").is_err());
+}
+
+#[test]
+fn exclusive_zone_accepts_minus_one_and_up() {
+    let cfg: Config = toml::from_str("items = []\n").unwrap();
+    assert_eq!(cfg.bar.exclusive_zone, None);
+
+    for zone in [-1, 0, 40] {
+        let cfg: Config =
+            toml::from_str(&format!("items = []\n[bar]\nexclusive_zone = {zone}\n")).unwrap();
+        assert_eq!(cfg.bar.exclusive_zone, Some(zone));
+        assert!(cfg.bar.validate().is_ok());
+    }
+
+    let cfg: Config = toml::from_str("items = []\n[bar]\nexclusive_zone = -2\n").unwrap();
+    let err = cfg.bar.validate().unwrap_err();
+    assert!(err.to_string().contains("exclusive_zone"));
+}
+
+#[test]
+fn keyboard_interactivity_defaults_to_none() {
+    let cfg: Config = toml::from_str("items = []\n").unwrap();
+    assert_eq!(cfg.bar.keyboard_interactivity, KeyboardInteractivity::None);
+
+    let cfg: Config =
+        toml::from_str("items = []\n[bar]\nkeyboard_interactivity = \"on-demand\"\n").unwrap();
+    assert_eq!(
+        cfg.bar.keyboard_interactivity,
+        KeyboardInteractivity::OnDemand
+    );
+    assert!(toml::from_str::<Config>("[bar]\nkeyboard_interactivity = \"always\"\n").is_err());
+}
+
+#[test]
+fn icon_position_orders_children() {
+    let cfg: Config =
+        toml::from_str("items = [\"text/a\"]\n[\"text/a\"]\nicon_position = \"right\"\n").unwrap();
+    let common: ItemCommon = cfg.module("text/a").parse().unwrap();
+    assert_eq!(common.icon_position, IconPosition::Right);
+    assert_eq!(
+        common.icon_position.order("icon", "label"),
+        ["label", "icon"]
+    );
+
+    let common: ItemCommon = cfg.module("clock").parse().unwrap();
+    assert_eq!(
+        common.icon_position.order("icon", "label"),
+        ["icon", "label"]
+    );
+}
+
+#[test]
+fn display_picks_icon_or_label() {
+    let cfg: Config =
+        toml::from_str("items = [\"clock\"]\n[clock]\ndisplay = \"label\"\n").unwrap();
+    let common: ItemCommon = cfg.module("clock").parse().unwrap();
+    assert_eq!(common.display, ItemDisplay::Label);
+    assert!(common.display.label() && !common.display.icon());
+
+    assert!(ItemDisplay::default().icon() && ItemDisplay::default().label());
+    assert!(ItemDisplay::Icon.icon() && !ItemDisplay::Icon.label());
+}
+
+#[test]
+fn level_display_sits_beside_item_display() {
+    let cfg: Config = toml::from_str(
+        "items = [\"custom/disk\"]\n[\"custom/disk\"]\ncommand = \"df\"\n\
+         display = \"label\"\nlevel = { display = \"bar\", warn = 80 }\n",
+    )
+    .unwrap();
+    let custom: CustomConfig = cfg.module("custom/disk").parse().unwrap();
+    assert_eq!(custom.common.display, ItemDisplay::Label);
+    assert_eq!(custom.level.display, LevelDisplay::Bar);
+    assert_eq!(custom.level.warn, 80.0);
+}
@@ -7,7 +7,8 @@ use panel_rs::core::item_manager::ItemManager;
 fn load_clock_item() {
     let cfg = Config {
         items: vec!["bar".into(), "clock".into()],
-        refresh_secs: 1,
+        refresh_secs: 1.0,
+        ..Default::default()
     };
     let manager = ItemManager::load(&cfg);
     assert_eq!(manager.items().len(), 1);
@@ -18,10 +19,30 @@ fn load_clock_item() {
 fn skip_unknown_items() {
     let cfg = Config {
         items: vec!["foo".into(), "clock".into()],
-        refresh_secs: 1,
+        refresh_secs: 1.0,
+        ..Default::default()
     };
     let manager = ItemManager::load(&cfg);
     // "foo" is unknown an should be skipped
     assert_eq!(manager.items().len(), 1);
     assert_eq!(manager.items()[0].name(), "clock");
 }
+
+#[test]
+fn load_text_instances() {
+    let cfg: Config = toml::from_str(
+        r#"
+        items = ["text/left", "clock", "text/right"]
+
+        ["text/left"]
+        text = "["
+
+        ["text/right"]
+        text = "]"
+        "#,
+    )
+    .unwrap();
+    let manager = ItemManager::load(&cfg);
+    let names: Vec<_> = manager.items().iter().map(|i| i.name()).collect();
+    assert_eq!(names, ["text/left", "clock", "text/right"]);
+}